@@ -0,0 +1,23 @@
+use fixtures::fixtures;
+
+#[fixtures(["tests/fixtures/async_fns/*.txt"])]
+#[tokio::test]
+async fn test(contents: &str) {
+    assert_eq!(count_words(contents, String::new()).await, 2);
+}
+
+#[fixtures(["tests/fixtures/async_fns/*.txt"])]
+async fn count_words(contents: &str, _separator: String) -> usize {
+    contents.split_whitespace().count()
+}
+
+fn main() {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    runtime.block_on(async {
+        for expansion in count_words::EXPANSIONS {
+            assert_eq!(expansion(String::from(" ")).await, 2);
+        }
+    });
+}
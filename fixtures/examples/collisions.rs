@@ -0,0 +1,7 @@
+use fixtures::fixtures;
+
+#[fixtures(["tests/fixtures/collisions/*.txt"])]
+#[test]
+fn test(_path: &std::path::Path) {}
+
+fn main() {}
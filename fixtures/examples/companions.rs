@@ -0,0 +1,11 @@
+use fixtures::fixtures;
+#[cfg(test)]
+use fixtures::Fixture;
+
+#[fixtures(["tests/fixtures/companions/*"], companions = ["stderr", "args?"])]
+#[test]
+fn test(fixture: Fixture) {
+    assert!(fixture.companion("stderr").is_some());
+}
+
+fn main() {}
@@ -0,0 +1,33 @@
+use fixtures::fixtures;
+
+#[cfg(test)]
+mod fast {
+    pub fn parse(input: &str) -> Vec<u32> {
+        input
+            .split_ascii_whitespace()
+            .map(|word| word.parse().expect("Expected a number"))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod reference {
+    pub fn parse(input: &str) -> Vec<u32> {
+        let mut numbers = Vec::new();
+        for word in input.split(' ') {
+            let word = word.trim();
+            if !word.is_empty() {
+                numbers.push(word.parse().expect("Expected a number"));
+            }
+        }
+        numbers
+    }
+}
+
+#[fixtures(["tests/fixtures/compare/*.txt"], compare = [fast::parse, reference::parse])]
+#[test]
+fn parse(input: &str) {
+    assert!(input.ends_with('\n'));
+}
+
+fn main() {}
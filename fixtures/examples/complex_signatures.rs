@@ -2,7 +2,7 @@ use std::path::Path;
 
 use fixtures::fixtures;
 
-#[fixtures(["fixtures/tests/fixtures/complex_signatures/*.txt"])]
+#[fixtures(["tests/fixtures/complex_signatures/*.txt"])]
 fn complex_fn(
     _path: &Path,
     _owned: String,
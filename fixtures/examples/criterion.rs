@@ -4,7 +4,7 @@ use fixtures::fixtures;
 
 struct Criterion;
 
-#[fixtures(["fixtures/tests/fixtures/criterion/*.txt"])]
+#[fixtures(["tests/fixtures/criterion/*.txt"])]
 fn bench(_path: &Path, _c: &mut Criterion) {}
 
 fn benches() {
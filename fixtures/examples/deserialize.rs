@@ -0,0 +1,24 @@
+use std::collections::BTreeMap;
+
+use fixtures::{fixtures, format::Json};
+
+type Case = BTreeMap<String, String>;
+
+#[fixtures(["tests/fixtures/deserialize/*.json"])]
+fn wrapper_type(_case: Json<Case>) {}
+
+#[fixtures(["tests/fixtures/deserialize/*.toml"], format = "toml")]
+fn explicit_format(_case: Case) {}
+
+#[fixtures(["tests/fixtures/deserialize/*"], format = "auto")]
+fn auto_format(_case: Case) {}
+
+fn main() {
+    for expansion in wrapper_type::EXPANSIONS
+        .iter()
+        .chain(explicit_format::EXPANSIONS)
+        .chain(auto_format::EXPANSIONS)
+    {
+        expansion();
+    }
+}
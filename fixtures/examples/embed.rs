@@ -0,0 +1,16 @@
+use fixtures::{fixtures, EmbeddedFixture};
+
+#[fixtures(["tests/fixtures/embed/*.txt"], embed = true)]
+fn embedded_fixture(_fixture: EmbeddedFixture) {}
+
+#[fixtures(["tests/fixtures/embed/*.txt"], embed = true)]
+fn embedded_contents(_contents: String) {}
+
+#[fixtures(["tests/fixtures/embed/*.txt"], embed = true)]
+fn embedded_str(_contents: &'static str) {}
+
+fn main() {
+    embedded_fixture::file_1_dot_txt();
+    embedded_contents::file_1_dot_txt();
+    embedded_str::file_1_dot_txt();
+}
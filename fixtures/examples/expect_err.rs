@@ -0,0 +1,23 @@
+use fixtures::fixtures;
+
+#[fixtures(["tests/fixtures/expect_err/**/*.txt"])]
+#[fixtures::expect_err("tests/fixtures/expect_err/invalid/*.txt")]
+#[test]
+fn test(contents: &str) -> Result<(), std::num::ParseIntError> {
+    contents.trim().parse::<u32>()?;
+    Ok(())
+}
+
+#[fixtures(["tests/fixtures/expect_err/**/*.txt"])]
+#[fixtures::expect_err(
+    paths = "tests/fixtures/expect_err/invalid/letter.txt",
+    matches = "^invalid digit"
+)]
+#[fixtures::ignore("tests/fixtures/expect_err/invalid/empty.txt")]
+#[test]
+fn test_matches(contents: &str) -> Result<(), std::num::ParseIntError> {
+    contents.trim().parse::<u32>()?;
+    Ok(())
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+use std::{fs::File, path::Path};
+
+use fixtures::{fixtures, FromFixture};
+
+#[allow(dead_code)]
+struct Contents(String);
+
+impl FromFixture for Contents {
+    type Error = std::io::Error;
+
+    fn from_fixture(path: &'static Path) -> Result<Self, Self::Error> {
+        std::fs::read_to_string(path).map(Contents)
+    }
+}
+
+#[fixtures(["tests/fixtures/from_fixture/*.txt"])]
+fn custom_type(_contents: Contents) {}
+
+#[fixtures(["tests/fixtures/from_fixture/*.txt"])]
+fn file(_file: File) {}
+
+fn main() {
+    custom_type::file_1_dot_txt();
+    file::file_1_dot_txt();
+}
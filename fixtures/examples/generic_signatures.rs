@@ -0,0 +1,52 @@
+use std::{fmt::Display, path::Path};
+
+use fixtures::fixtures;
+
+#[fixtures(["tests/fixtures/generic_signatures/*.txt"])]
+pub fn generic_fn<T>(path: &Path, value: T) -> String
+where
+    T: Display,
+{
+    format!("{}: {value}", path.display())
+}
+
+#[fixtures(["tests/fixtures/generic_signatures/*.txt"])]
+fn impl_trait_fn(_path: &Path, value: impl Display) -> String {
+    value.to_string()
+}
+
+#[fixtures(["tests/fixtures/generic_signatures/*.txt"])]
+fn lifetime_fn<'a>(_path: &Path, value: &'a str) -> &'a str {
+    value
+}
+
+#[fixtures(["tests/fixtures/generic_signatures/*.txt"])]
+const unsafe fn unsafe_fn(_path: &Path, value: usize) -> usize {
+    value
+}
+
+#[fixtures(["tests/fixtures/generic_signatures/*.txt"])]
+fn impl_trait_output_fn(path: &Path) -> impl Display {
+    path.display().to_string()
+}
+
+fn main() {
+    generic_fn::file_1_dot_txt(1);
+    impl_trait_fn::file_1_dot_txt("value");
+    for expansion in generic_fn::EXPANSIONS {
+        expansion.call(1);
+    }
+    for expansion in impl_trait_fn::EXPANSIONS {
+        expansion.call("value");
+    }
+    for expansion in impl_trait_output_fn::EXPANSIONS {
+        expansion.call().to_string();
+    }
+    for expansion in lifetime_fn::EXPANSIONS {
+        expansion("value");
+    }
+    for expansion in unsafe_fn::EXPANSIONS {
+        // SAFETY: `unsafe_fn` doesn't have any safety requirements
+        unsafe { expansion(1) };
+    }
+}
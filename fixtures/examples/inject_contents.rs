@@ -0,0 +1,20 @@
+use fixtures::fixtures;
+
+#[fixtures(["tests/fixtures/inject_contents/*.txt"])]
+fn str_contents(_contents: &'static str) {}
+
+#[fixtures(["tests/fixtures/inject_contents/*.txt"])]
+fn bytes_contents(_contents: &'static [u8]) {}
+
+#[fixtures(["tests/fixtures/inject_contents/*.txt"])]
+fn string_contents(_contents: String) {}
+
+#[fixtures(["tests/fixtures/inject_contents/*.txt"])]
+fn vec_contents(_contents: Vec<u8>) {}
+
+fn main() {
+    str_contents::file_1_dot_txt();
+    bytes_contents::file_1_dot_txt();
+    string_contents::file_1_dot_txt();
+    vec_contents::file_1_dot_txt();
+}
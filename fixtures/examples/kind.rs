@@ -0,0 +1,18 @@
+#[cfg(test)]
+use std::path::Path;
+
+use fixtures::fixtures;
+
+#[fixtures(["tests/fixtures/kind/**/*"], kind = "file")]
+#[test]
+fn files(_path: &Path) {}
+
+#[fixtures(["tests/fixtures/kind/*"], kind = "dir")]
+#[test]
+fn dirs(_path: &Path) {}
+
+#[fixtures(["tests/fixtures/kind/**/*"], kind = "any")]
+#[test]
+fn any(_path: &Path) {}
+
+fn main() {}
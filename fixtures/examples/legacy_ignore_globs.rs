@@ -4,9 +4,9 @@ use std::path::Path;
 use fixtures::fixtures;
 
 #[fixtures(
-    ["fixtures/tests/fixtures/legacy_ignore_globs/*.txt"],
+    ["tests/fixtures/legacy_ignore_globs/*.txt"],
     ignore = {
-        paths = ["fixtures/tests/fixtures/legacy_ignore_globs/*.ignore.txt"],
+        paths = ["tests/fixtures/legacy_ignore_globs/*.ignore.txt"],
         reason = "reason for ignoring file",
     },
 )]
@@ -14,8 +14,8 @@ use fixtures::fixtures;
 fn test1(_path: &Path) {}
 
 #[fixtures(
-    ["fixtures/tests/fixtures/legacy_ignore_globs/*.txt"],
-    ignore = ["fixtures/tests/fixtures/legacy_ignore_globs/*.ignore.txt"],
+    ["tests/fixtures/legacy_ignore_globs/*.txt"],
+    ignore = ["tests/fixtures/legacy_ignore_globs/*.ignore.txt"],
 )]
 #[test]
 fn test2(_path: &Path) {}
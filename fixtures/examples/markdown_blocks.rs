@@ -0,0 +1,9 @@
+use fixtures::fixtures;
+
+#[fixtures(["tests/fixtures/markdown_blocks/*.md"], markdown_blocks = "example")]
+#[test]
+fn test(block: fixtures::MarkdownBlock) {
+    assert!(block.contents().contains("foo"));
+}
+
+fn main() {}
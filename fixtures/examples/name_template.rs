@@ -0,0 +1,11 @@
+use fixtures::fixtures;
+
+#[fixtures(["tests/fixtures/name_template/*/*.txt"], name = "{dir}_{stem}", module = "cases")]
+#[test]
+fn test(_path: &std::path::Path) {}
+
+#[fixtures(["tests/fixtures/name_template/*/case_*.txt"], name = "{2}_{1}")]
+#[test]
+fn test_captures(_path: &std::path::Path) {}
+
+fn main() {}
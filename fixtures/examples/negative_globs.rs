@@ -4,8 +4,8 @@ use std::path::Path;
 use fixtures::fixtures;
 
 #[fixtures([
-    "fixtures/tests/fixtures/negative_globs/*.txt",
-    "!fixtures/tests/fixtures/negative_globs/*.skip.txt",
+    "tests/fixtures/negative_globs/*.txt",
+    "!tests/fixtures/negative_globs/*.skip.txt",
 ])]
 #[test]
 fn test(_path: &Path) {}
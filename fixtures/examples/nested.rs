@@ -0,0 +1,7 @@
+use fixtures::fixtures;
+
+#[fixtures(["tests/fixtures/nested/**/*.txt"], nested = true)]
+#[test]
+fn test(_path: &std::path::Path) {}
+
+fn main() {}
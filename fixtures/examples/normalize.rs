@@ -0,0 +1,17 @@
+use fixtures::fixtures;
+#[cfg(test)]
+use fixtures::normalize::Normalizer;
+
+#[cfg(test)]
+fn normalizer() -> Normalizer {
+    Normalizer::standard().replace(r"\d+ms", "[DURATION]")
+}
+
+#[fixtures(["tests/fixtures/normalize/*.txt"], normalize = normalizer())]
+#[test]
+fn test(path: &std::path::Path) {
+    let output = std::fs::read_to_string(path).unwrap();
+    fixtures::golden::assert_golden(path, output);
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+use std::path::Path;
+
+use fixtures::fixtures;
+
+#[fixtures(["tests/fixtures/path_style/*.txt"], path_style = "absolute")]
+fn absolute(_path: &Path) {}
+
+#[fixtures(["tests/fixtures/path_style/*.txt"], path_style = "manifest")]
+fn manifest(_path: &Path) {}
+
+#[fixtures(["tests/fixtures/path_style/*.txt"], path_style = "env")]
+fn env(_path: &Path) {}
+
+#[fixtures(["tests/fixtures/path_style/*.txt"], path_style = "env")]
+fn env_contents(_contents: &str) {}
+
+fn main() {
+    absolute::file_1_dot_txt();
+    manifest::file_1_dot_txt();
+    env::file_1_dot_txt();
+    env_contents::file_1_dot_txt();
+}
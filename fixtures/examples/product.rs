@@ -0,0 +1,13 @@
+use fixtures::fixtures;
+
+#[fixtures(
+    ["tests/fixtures/product/configs/*.toml"],
+    ["tests/fixtures/product/inputs/*.txt"],
+)]
+#[test]
+fn test(config: &std::path::Path, input: &str) {
+    assert!(config.exists());
+    assert!(input.starts_with("input"));
+}
+
+fn main() {}
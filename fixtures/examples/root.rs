@@ -0,0 +1,15 @@
+#[cfg(test)]
+use std::path::Path;
+
+use fixtures::fixtures;
+
+#[fixtures(["tests/fixtures/root/*.txt"], root = "manifest")]
+#[test]
+fn manifest(_path: &Path) {}
+
+#[fixtures(["fixtures/tests/fixtures/root/*.txt"], root = "workspace")]
+#[fixtures::ignore("fixtures/tests/fixtures/root/*.txt")]
+#[test]
+fn workspace(_path: &Path) {}
+
+fn main() {}
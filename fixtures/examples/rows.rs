@@ -0,0 +1,17 @@
+use fixtures::fixtures;
+
+#[fixtures(["tests/fixtures/rows/*.csv"], rows = true, row_name = "name")]
+#[test]
+fn test_csv((name, input, expected): (&str, &str, i64)) {
+    assert!(!name.is_empty());
+    assert!(!input.is_empty());
+    assert!(expected <= 3);
+}
+
+#[fixtures(["tests/fixtures/rows/*.jsonl"], rows = true)]
+#[test]
+fn test_json_lines(row: fixtures::Row) {
+    assert!(row.get("input").is_some());
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+use fixtures::fixtures;
+
+#[fixtures(["tests/fixtures/should_panic/**/*.txt"])]
+#[fixtures::should_panic(
+    paths = "tests/fixtures/should_panic/invalid/*.txt",
+    expected = "parse error"
+)]
+#[test]
+fn test(contents: &str) {
+    contents.trim().parse::<u32>().expect("parse error");
+}
+
+#[fixtures(["tests/fixtures/should_panic/**/*.txt"])]
+#[cfg_attr(
+    test,
+    fixtures::should_panic("tests/fixtures/should_panic/invalid/empty.txt")
+)]
+#[fixtures::ignore("tests/fixtures/should_panic/invalid/letter.txt")]
+#[test]
+fn test_cfg_attr(contents: &str) {
+    contents.trim().parse::<u32>().unwrap();
+}
+
+fn main() {}
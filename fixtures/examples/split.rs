@@ -0,0 +1,15 @@
+use fixtures::fixtures;
+
+#[fixtures(["tests/fixtures/split/corpus.txt"], split = regex("^=== (?<name>.*) ===$"))]
+#[test]
+fn test(section: fixtures::Section) {
+    assert!(section.text().trim().parse::<i64>().is_ok());
+}
+
+#[fixtures(["tests/fixtures/split/cases.txt"], split = "---")]
+#[test]
+fn test_unnamed(section: fixtures::Section) {
+    assert!(section.line() > 0);
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+#[cfg(test)]
+use std::{path::Path, prelude::v1::test as custom_test};
+
+use fixtures::fixtures;
+
+#[fixtures(["tests/fixtures/test_attrs/*.txt"])]
+#[::core::prelude::v1::test]
+#[fixtures::ignore("tests/fixtures/test_attrs/file_2.txt")]
+fn builtin(_path: &Path) {}
+
+#[fixtures(["tests/fixtures/test_attrs/*.txt"], test_attrs = [custom_test])]
+#[custom_test]
+#[fixtures::ignore("tests/fixtures/test_attrs/file_2.txt")]
+fn custom(_path: &Path) {}
+
+fn main() {}
@@ -0,0 +1,20 @@
+use fixtures::fixtures;
+
+#[fixtures(["tests/fixtures/txtar/*.txtar"])]
+#[test]
+fn test(archive: fixtures::txtar::Archive) {
+    let dir = archive
+        .materialize()
+        .expect("Failed to materialize archive");
+    for name in archive.names() {
+        let contents =
+            std::fs::read_to_string(dir.path().join(name)).expect("Failed to read member");
+        assert_eq!(Some(contents.as_str()), archive.get(name));
+    }
+    assert_eq!(
+        fixtures::txtar::Archive::parse(&archive.to_string()),
+        archive
+    );
+}
+
+fn main() {}
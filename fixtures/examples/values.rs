@@ -0,0 +1,20 @@
+use fixtures::fixtures;
+
+#[fixtures(["tests/fixtures/values/*.rs"], values(opt = [0, 1, 2, 3]))]
+#[test]
+fn single(contents: &str, opt: u8) {
+    assert!(!contents.is_empty());
+    assert!(opt <= 3);
+}
+
+#[fixtures(
+    ["tests/fixtures/values/*.rs"],
+    values(target = ["x86_64", "wasm32"], debug = [true, false]),
+)]
+#[test]
+fn multiple(contents: &str, debug: bool, target: &str) {
+    assert!(!contents.is_empty());
+    assert!(debug || !target.is_empty());
+}
+
+fn main() {}
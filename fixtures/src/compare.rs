@@ -0,0 +1,42 @@
+//! Differential testing of several implementations against the same fixtures.
+//!
+//! The `compare` option calls each of the given functions with the fixture, and asserts that they all return the same
+//! output, before calling the decorated function as usual.
+//!
+//! ```rs
+//! #[fixtures(["corpus/*"], compare = [fast::parse, reference::parse])]
+//! #[test]
+//! fn parse(input: &str) {}
+//! ```
+//!
+//! Outputs are compared using `PartialEq`, and a mismatch is reported with a diff of their `Debug` representations.
+
+use std::fmt::Debug;
+
+use similar::TextDiff;
+
+/// Asserts that the outputs of all implementations are equal, panicking with a diff of each output which differs from
+/// the first.
+///
+/// Each output is paired with the name of the implementation which produced it.
+#[track_caller]
+pub fn assert_agree<T: PartialEq + Debug, const N: usize>(outputs: [(&str, T); N]) {
+    let Some(((expected_name, expected), rest)) = outputs.split_first() else {
+        return;
+    };
+    let expected_debug = format!("{expected:#?}\n");
+    let mismatches = rest
+        .iter()
+        .filter(|(_, actual)| actual != expected)
+        .map(|(actual_name, actual)| {
+            let diff = TextDiff::from_lines(expected_debug.as_str(), &format!("{actual:#?}\n"))
+                .unified_diff()
+                .header(expected_name, actual_name)
+                .to_string();
+            format!("`{actual_name}` disagrees with `{expected_name}`:\n{diff}")
+        })
+        .collect::<Vec<_>>();
+    if !mismatches.is_empty() {
+        panic!("{}", mismatches.join("\n"));
+    }
+}
@@ -0,0 +1,135 @@
+use std::{convert::Infallible, fmt::Display, marker::PhantomData, path::Path, str::Utf8Error};
+
+use crate::FromFixture;
+
+/// A fixture which was embedded into the binary at compile time, using `#[fixtures(..., embed = true)]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EmbeddedFixture {
+    path: &'static str,
+    contents: &'static [u8],
+}
+
+impl EmbeddedFixture {
+    #[doc(hidden)]
+    pub const fn new(path: &'static str, contents: &'static [u8]) -> Self {
+        Self { path, contents }
+    }
+
+    /// The path of the fixture, relative to the directory the glob patterns were resolved against.
+    pub fn path(&self) -> &'static Path {
+        Path::new(self.path)
+    }
+
+    /// The contents of the fixture.
+    pub fn contents(&self) -> &'static [u8] {
+        self.contents
+    }
+
+    /// The contents of the fixture as a string.
+    pub fn contents_str(&self) -> Result<&'static str, Utf8Error> {
+        std::str::from_utf8(self.contents)
+    }
+}
+
+/// Loads the value passed to a function decorated with `#[fixtures(..., embed = true)]` from an embedded fixture.
+///
+/// This is the equivalent of [`FromFixture`](crate::FromFixture) for embedded fixtures.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be loaded from an embedded fixture",
+    label = "`{Self}` doesn't implement `FromEmbedded`",
+    note = "set `embed = false` to load the fixture from the filesystem at runtime"
+)]
+pub trait FromEmbedded: Sized {
+    /// The error returned when the fixture can't be loaded.
+    type Error: Display;
+
+    fn from_embedded(fixture: EmbeddedFixture) -> Result<Self, Self::Error>;
+}
+
+impl FromEmbedded for EmbeddedFixture {
+    type Error = Infallible;
+
+    fn from_embedded(fixture: EmbeddedFixture) -> Result<Self, Self::Error> {
+        Ok(fixture)
+    }
+}
+
+impl FromEmbedded for &'static [u8] {
+    type Error = Infallible;
+
+    fn from_embedded(fixture: EmbeddedFixture) -> Result<Self, Self::Error> {
+        Ok(fixture.contents())
+    }
+}
+
+impl FromEmbedded for &'static str {
+    type Error = Utf8Error;
+
+    fn from_embedded(fixture: EmbeddedFixture) -> Result<Self, Self::Error> {
+        fixture.contents_str()
+    }
+}
+
+impl FromEmbedded for Vec<u8> {
+    type Error = Infallible;
+
+    fn from_embedded(fixture: EmbeddedFixture) -> Result<Self, Self::Error> {
+        Ok(fixture.contents().to_vec())
+    }
+}
+
+impl FromEmbedded for String {
+    type Error = Utf8Error;
+
+    fn from_embedded(fixture: EmbeddedFixture) -> Result<Self, Self::Error> {
+        fixture.contents_str().map(String::from)
+    }
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn from_embedded<T: FromEmbedded>(fixture: EmbeddedFixture) -> T {
+    match T::from_embedded(fixture) {
+        Ok(value) => value,
+        Err(err) => panic!(
+            "failed to load embedded fixture `{}`: {err}",
+            fixture.path().display()
+        ),
+    }
+}
+
+/// Loads a fixture of type `T` in embedded mode. Types which implement [`FromEmbedded`] are loaded from the embedded
+/// fixture, and other types fall back to [`FromFixture`], using autoref specialization.
+#[doc(hidden)]
+pub struct Loader<T>(PhantomData<T>);
+
+impl<T> Loader<T> {
+    #[allow(clippy::new_without_default)]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+#[doc(hidden)]
+pub trait LoadEmbedded<T> {
+    fn load(&self, fixture: EmbeddedFixture, path: impl FnOnce() -> &'static Path) -> T;
+}
+
+impl<T: FromEmbedded> LoadEmbedded<T> for Loader<T> {
+    #[track_caller]
+    fn load(&self, fixture: EmbeddedFixture, _path: impl FnOnce() -> &'static Path) -> T {
+        from_embedded(fixture)
+    }
+}
+
+#[doc(hidden)]
+pub trait LoadFixture<T> {
+    fn load(&self, fixture: EmbeddedFixture, path: impl FnOnce() -> &'static Path) -> T;
+}
+
+impl<T: FromFixture> LoadFixture<T> for &Loader<T> {
+    #[track_caller]
+    fn load(&self, _fixture: EmbeddedFixture, path: impl FnOnce() -> &'static Path) -> T {
+        crate::from_fixture::from_fixture(path())
+    }
+}
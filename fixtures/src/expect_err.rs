@@ -0,0 +1,30 @@
+use std::fmt::Display;
+
+use regex::Regex;
+
+/// Checks the result of a test which `#[fixtures::expect_err]` expects to return an error, panicking if it returned
+/// `Ok`, or if the error doesn't match the `matches` pattern.
+///
+/// The expected error is converted to `Ok`, so that the test passes.
+#[doc(hidden)]
+#[track_caller]
+pub fn expect_err<T: Default, E: Display>(
+    result: Result<T, E>,
+    matches: Option<&str>,
+) -> Result<T, E> {
+    let err = match result {
+        Ok(_) => panic!("expected the test to return an error, but it returned `Ok`"),
+        Err(err) => err,
+    };
+    if let Some(pattern) = matches {
+        let regex = match Regex::new(pattern) {
+            Ok(regex) => regex,
+            Err(err) => panic!("invalid pattern `{pattern}`: {err}"),
+        };
+        let message = err.to_string();
+        if !regex.is_match(&message) {
+            panic!("expected an error matching `{pattern}`, but the test returned: {message}");
+        }
+    }
+    Ok(T::default())
+}
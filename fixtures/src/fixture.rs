@@ -0,0 +1,57 @@
+use std::{convert::Infallible, path::Path};
+
+use crate::FromFixture;
+
+/// A fixture, along with the companion files which share its stem.
+///
+/// Companion files are declared using the `companions` option, and are looked up by their extension.
+///
+/// ```rs
+/// #[fixtures(["ui/*.rs"], companions = ["stderr", "args?"])]
+/// #[test]
+/// fn test(fixture: fixtures::Fixture) {
+///     let stderr = fixture.companion("stderr").unwrap();
+///     let args = fixture.companion("args");
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fixture {
+    path: &'static Path,
+    companions: Vec<(&'static str, &'static Path)>,
+}
+
+impl Fixture {
+    #[doc(hidden)]
+    pub fn new(path: &'static Path, companions: Vec<(&'static str, &'static Path)>) -> Self {
+        Self { path, companions }
+    }
+
+    /// The path of the fixture.
+    pub fn path(&self) -> &'static Path {
+        self.path
+    }
+
+    /// The path of the companion file with the given extension, if it exists.
+    ///
+    /// Companions which are marked as required are guaranteed to exist, as they are checked at compile time.
+    pub fn companion(&self, extension: &str) -> Option<&'static Path> {
+        let extension = extension.strip_prefix('.').unwrap_or(extension);
+        self.companions
+            .iter()
+            .find(|(companion_extension, _)| *companion_extension == extension)
+            .map(|(_, path)| *path)
+    }
+
+    /// The extensions and paths of all companion files which exist.
+    pub fn companions(&self) -> impl Iterator<Item = (&'static str, &'static Path)> + '_ {
+        self.companions.iter().copied()
+    }
+}
+
+impl FromFixture for Fixture {
+    type Error = Infallible;
+
+    fn from_fixture(path: &'static Path) -> Result<Self, Self::Error> {
+        Ok(Fixture::new(path, Vec::new()))
+    }
+}
@@ -0,0 +1,26 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+/// The environment variable used to resolve fixtures at runtime, when using `path_style = "env"`.
+///
+/// Relative fixture paths are resolved against `FIXTURES_ROOT` if it is set, otherwise against `CARGO_MANIFEST_DIR`, and
+/// otherwise against the current directory.
+pub const FIXTURES_ROOT: &str = "FIXTURES_ROOT";
+
+/// Resolves the fixture at `manifest_relative_path`. Each generated expression passes its own `cell`, so the path is
+/// resolved once, when it's first used.
+#[doc(hidden)]
+pub fn fixture_path(
+    cell: &'static OnceLock<PathBuf>,
+    manifest_relative_path: &str,
+) -> &'static Path {
+    cell.get_or_init(|| {
+        let root = std::env::var_os(FIXTURES_ROOT)
+            .or_else(|| std::env::var_os("CARGO_MANIFEST_DIR"))
+            .map(PathBuf::from)
+            .unwrap_or_default();
+        root.join(manifest_relative_path)
+    })
+}
@@ -0,0 +1,308 @@
+//! Deserialization of fixtures using [`serde`](https://serde.rs).
+//!
+//! Each format is enabled by the cargo feature of the same name (`json`, `toml`, `yaml` and `ron`).
+
+use std::{
+    fmt::{self, Display},
+    ops::{Deref, DerefMut},
+    path::Path,
+};
+
+use serde::de::DeserializeOwned;
+
+use crate::{EmbeddedFixture, FromEmbedded, FromFixture};
+
+/// A serialization format supported by `fixtures`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    #[cfg(feature = "json")]
+    Json,
+    #[cfg(feature = "toml")]
+    Toml,
+    #[cfg(feature = "yaml")]
+    Yaml,
+    #[cfg(feature = "ron")]
+    Ron,
+}
+
+impl Format {
+    /// Returns the format associated with the file extension, if the corresponding feature is enabled.
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension {
+            #[cfg(feature = "json")]
+            "json" => Some(Format::Json),
+            #[cfg(feature = "toml")]
+            "toml" => Some(Format::Toml),
+            #[cfg(feature = "yaml")]
+            "yaml" | "yml" => Some(Format::Yaml),
+            #[cfg(feature = "ron")]
+            "ron" => Some(Format::Ron),
+            _ => None,
+        }
+    }
+
+    /// Returns the format associated with the extension of the path, if the corresponding feature is enabled.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        path.extension()
+            .and_then(|extension| extension.to_str())
+            .and_then(Self::from_extension)
+    }
+
+    /// Deserializes a value from a string in this format.
+    pub fn deserialize<T: DeserializeOwned>(self, contents: &str) -> Result<T, DeserializeError> {
+        match self {
+            #[cfg(feature = "json")]
+            Format::Json => serde_json::from_str(contents).map_err(|err| {
+                DeserializeError::new(self, err.to_string())
+                    .with_location(Some((err.line(), err.column())))
+            }),
+            #[cfg(feature = "toml")]
+            Format::Toml => toml::from_str(contents).map_err(|err| {
+                DeserializeError::new(self, err.message().to_string())
+                    .with_location(err.span().map(|span| line_and_column(contents, span.start)))
+            }),
+            #[cfg(feature = "yaml")]
+            Format::Yaml => serde_yaml::from_str(contents).map_err(|err| {
+                DeserializeError::new(self, err.to_string()).with_location(
+                    err.location()
+                        .map(|location| (location.line(), location.column())),
+                )
+            }),
+            #[cfg(feature = "ron")]
+            Format::Ron => ron::from_str(contents).map_err(|err| {
+                DeserializeError::new(self, err.code.to_string())
+                    .with_location(Some((err.span.start.line, err.span.start.col)))
+            }),
+        }
+    }
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            #[cfg(feature = "json")]
+            Format::Json => "JSON",
+            #[cfg(feature = "toml")]
+            Format::Toml => "TOML",
+            #[cfg(feature = "yaml")]
+            Format::Yaml => "YAML",
+            #[cfg(feature = "ron")]
+            Format::Ron => "RON",
+        };
+        f.write_str(name)
+    }
+}
+
+/// The error returned when a fixture can't be deserialized.
+#[derive(Debug)]
+pub struct DeserializeError {
+    format: Option<Format>,
+    message: String,
+    location: Option<(usize, usize)>,
+}
+
+impl DeserializeError {
+    fn new(format: Format, message: String) -> Self {
+        Self {
+            format: Some(format),
+            message,
+            location: None,
+        }
+    }
+
+    fn with_location(mut self, location: Option<(usize, usize)>) -> Self {
+        if let Some((line, column)) = location {
+            // Some formats include the location in the message
+            let suffix = format!(" at line {line} column {column}");
+            if let Some(message) = self.message.strip_suffix(&suffix) {
+                self.message = message.to_string();
+            }
+        }
+        self.location = location;
+        self
+    }
+
+    /// The format of the fixture, if it could be determined.
+    pub fn format(&self) -> Option<Format> {
+        self.format
+    }
+
+    /// The error reported by the deserializer.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The line (starting at 1) at which deserialization failed, if known.
+    pub fn line(&self) -> Option<usize> {
+        self.location.map(|(line, _)| line)
+    }
+
+    /// The column (starting at 1) at which deserialization failed, if known.
+    pub fn column(&self) -> Option<usize> {
+        self.location.map(|(_, column)| column)
+    }
+}
+
+impl Display for DeserializeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.format {
+            Some(format) => write!(f, "failed to deserialize {format}")?,
+            None => write!(f, "failed to deserialize fixture")?,
+        }
+        if let Some((line, column)) = self.location {
+            write!(f, " at line {line}, column {column}")?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+impl std::error::Error for DeserializeError {}
+
+fn read_to_string(path: &Path, format: Option<Format>) -> Result<String, DeserializeError> {
+    std::fs::read_to_string(path).map_err(|err| DeserializeError {
+        format,
+        message: err.to_string(),
+        location: None,
+    })
+}
+
+fn embedded_contents(
+    fixture: EmbeddedFixture,
+    format: Option<Format>,
+) -> Result<&'static str, DeserializeError> {
+    fixture.contents_str().map_err(|err| DeserializeError {
+        format,
+        message: err.to_string(),
+        location: None,
+    })
+}
+
+#[cfg(feature = "toml")]
+fn line_and_column(contents: &str, offset: usize) -> (usize, usize) {
+    let before = &contents[..offset.min(contents.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before
+        .rfind('\n')
+        .map_or(before, |index| &before[index + 1..])
+        .chars()
+        .count()
+        + 1;
+    (line, column)
+}
+
+macro_rules! format_wrapper {
+    ($(#[$meta:meta])* $feature:literal, $name:ident) => {
+        $(#[$meta])*
+        #[cfg(feature = $feature)]
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub struct $name<T>(pub T);
+
+        #[cfg(feature = $feature)]
+        impl<T> $name<T> {
+            pub fn into_inner(self) -> T {
+                self.0
+            }
+        }
+
+        #[cfg(feature = $feature)]
+        impl<T> Deref for $name<T> {
+            type Target = T;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        #[cfg(feature = $feature)]
+        impl<T> DerefMut for $name<T> {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+
+        #[cfg(feature = $feature)]
+        impl<T: DeserializeOwned> FromFixture for $name<T> {
+            type Error = DeserializeError;
+
+            fn from_fixture(path: &'static Path) -> Result<Self, Self::Error> {
+                let contents = read_to_string(path, Some(Format::$name))?;
+                Format::$name.deserialize(&contents).map($name)
+            }
+        }
+
+        #[cfg(feature = $feature)]
+        impl<T: DeserializeOwned> FromEmbedded for $name<T> {
+            type Error = DeserializeError;
+
+            fn from_embedded(fixture: EmbeddedFixture) -> Result<Self, Self::Error> {
+                let contents = embedded_contents(fixture, Some(Format::$name))?;
+                Format::$name.deserialize(contents).map($name)
+            }
+        }
+    };
+}
+
+format_wrapper!(
+    /// A fixture deserialized from JSON.
+    "json", Json);
+format_wrapper!(
+    /// A fixture deserialized from TOML.
+    "toml", Toml);
+format_wrapper!(
+    /// A fixture deserialized from YAML.
+    "yaml", Yaml);
+format_wrapper!(
+    /// A fixture deserialized from RON.
+    "ron", Ron);
+
+/// A fixture deserialized in the format matching its file extension.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deserialized<T>(pub T);
+
+impl<T> Deserialized<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Deserialized<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Deserialized<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T: DeserializeOwned> FromFixture for Deserialized<T> {
+    type Error = DeserializeError;
+
+    fn from_fixture(path: &'static Path) -> Result<Self, Self::Error> {
+        let format = format_from_path(path)?;
+        let contents = read_to_string(path, Some(format))?;
+        format.deserialize(&contents).map(Deserialized)
+    }
+}
+
+impl<T: DeserializeOwned> FromEmbedded for Deserialized<T> {
+    type Error = DeserializeError;
+
+    fn from_embedded(fixture: EmbeddedFixture) -> Result<Self, Self::Error> {
+        let format = format_from_path(fixture.path())?;
+        let contents = embedded_contents(fixture, Some(format))?;
+        format.deserialize(contents).map(Deserialized)
+    }
+}
+
+fn format_from_path(path: &Path) -> Result<Format, DeserializeError> {
+    Format::from_path(path).ok_or_else(|| DeserializeError {
+        format: None,
+        message: "unsupported file extension (is the corresponding feature enabled?)".to_string(),
+        location: None,
+    })
+}
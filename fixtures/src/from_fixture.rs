@@ -0,0 +1,90 @@
+use std::{
+    convert::Infallible,
+    fmt::Display,
+    fs::File,
+    io::{self, BufReader},
+    path::{Path, PathBuf},
+};
+
+/// Loads the value passed to a function decorated with `#[fixtures]` from the path of a fixture.
+///
+/// Implement this trait for your own types to have tests receive an already loaded value, rather than a path.
+///
+/// ```rs
+/// struct Config(String);
+///
+/// impl fixtures::FromFixture for Config {
+///     type Error = std::io::Error;
+///
+///     fn from_fixture(path: &'static std::path::Path) -> Result<Self, Self::Error> {
+///         std::fs::read_to_string(path).map(Config)
+///     }
+/// }
+///
+/// #[fixtures(["fixtures/*.toml"])]
+/// #[test]
+/// fn test(config: Config) {}
+/// ```
+pub trait FromFixture: Sized {
+    /// The error returned when the fixture can't be loaded.
+    type Error: Display;
+
+    fn from_fixture(path: &'static Path) -> Result<Self, Self::Error>;
+}
+
+impl FromFixture for &Path {
+    type Error = Infallible;
+
+    fn from_fixture(path: &'static Path) -> Result<Self, Self::Error> {
+        Ok(path)
+    }
+}
+
+impl FromFixture for PathBuf {
+    type Error = Infallible;
+
+    fn from_fixture(path: &'static Path) -> Result<Self, Self::Error> {
+        Ok(path.to_path_buf())
+    }
+}
+
+impl FromFixture for String {
+    type Error = io::Error;
+
+    fn from_fixture(path: &'static Path) -> Result<Self, Self::Error> {
+        std::fs::read_to_string(path)
+    }
+}
+
+impl FromFixture for Vec<u8> {
+    type Error = io::Error;
+
+    fn from_fixture(path: &'static Path) -> Result<Self, Self::Error> {
+        std::fs::read(path)
+    }
+}
+
+impl FromFixture for File {
+    type Error = io::Error;
+
+    fn from_fixture(path: &'static Path) -> Result<Self, Self::Error> {
+        File::open(path)
+    }
+}
+
+impl FromFixture for BufReader<File> {
+    type Error = io::Error;
+
+    fn from_fixture(path: &'static Path) -> Result<Self, Self::Error> {
+        File::open(path).map(BufReader::new)
+    }
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn from_fixture<T: FromFixture>(path: &'static Path) -> T {
+    match T::from_fixture(path) {
+        Ok(value) => value,
+        Err(err) => panic!("failed to load fixture `{}`: {err}", path.display()),
+    }
+}
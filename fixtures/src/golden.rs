@@ -0,0 +1,246 @@
+//! Comparison of test output with expected ("golden") files.
+//!
+//! By default, the expected output of a fixture is stored next to it, in a file with the same stem and the extension
+//! `expected` (e.g. `tests/fixtures/foo.expected` for `tests/fixtures/foo.txt`).
+//!
+//! ```rs
+//! #[fixtures(["tests/fixtures/*.txt"])]
+//! #[test]
+//! fn test(path: &std::path::Path) {
+//!     let output = run(std::fs::read_to_string(path).unwrap());
+//!     fixtures::golden::assert_golden(path, output);
+//! }
+//! ```
+//!
+//! When the `FIXTURES_BLESS` (or `UPDATE_EXPECT`) environment variable is set to `1`, the actual output is written to
+//! the expected file, instead of being compared with it. Otherwise, if the output doesn't match, the test panics with
+//! a unified diff, and the actual output is written to a file with the extension `actual` for inspection.
+//!
+//! The actual output can be normalized before it is compared or written, using a
+//! [`Normalizer`](crate::normalize::Normalizer).
+
+use std::{
+    fmt::{self, Display},
+    io,
+    path::{Path, PathBuf},
+};
+
+use similar::TextDiff;
+
+use crate::normalize::{self, Normalizer};
+
+/// The environment variable which causes expected files to be updated, rather than compared.
+pub const FIXTURES_BLESS: &str = "FIXTURES_BLESS";
+
+/// An alias for [`FIXTURES_BLESS`], for compatibility with `expect-test`.
+pub const UPDATE_EXPECT: &str = "UPDATE_EXPECT";
+
+/// The extension of expected files, unless another one is given using [`Golden::extension`].
+pub const DEFAULT_EXTENSION: &str = "expected";
+
+/// Compares `actual` with the expected file of `fixture`, panicking with a diff if they don't match.
+///
+/// See the [module documentation](self) for details.
+#[track_caller]
+pub fn assert_golden(fixture: impl AsRef<Path>, actual: impl AsRef<str>) {
+    Golden::new(fixture).assert(actual);
+}
+
+/// Returns whether expected files should be updated with the actual output, based on the [`FIXTURES_BLESS`] and
+/// [`UPDATE_EXPECT`] environment variables.
+pub fn is_bless() -> bool {
+    [FIXTURES_BLESS, UPDATE_EXPECT]
+        .into_iter()
+        .any(|name| std::env::var_os(name).is_some_and(|value| !value.is_empty() && value != "0"))
+}
+
+/// The expected output of a fixture.
+#[derive(Debug, Clone)]
+pub struct Golden {
+    path: PathBuf,
+    bless: bool,
+    normalizer: Option<Normalizer>,
+}
+
+impl Golden {
+    /// The expected output of `fixture`, stored next to it with the extension [`DEFAULT_EXTENSION`].
+    pub fn new(fixture: impl AsRef<Path>) -> Self {
+        Self::at(fixture.as_ref().with_extension(DEFAULT_EXTENSION))
+    }
+
+    /// The expected output stored at `path`.
+    ///
+    /// The actual output is normalized using the normalizer set by the `normalize` option of `#[fixtures]`, if any.
+    pub fn at(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            bless: is_bless(),
+            normalizer: normalize::current(),
+        }
+    }
+
+    /// Changes the extension of the expected file, e.g. to `stdout` or `stderr`.
+    pub fn extension(mut self, extension: &str) -> Self {
+        self.path.set_extension(extension);
+        self
+    }
+
+    /// Overrides whether the expected file is updated with the actual output, regardless of the environment.
+    pub fn bless(mut self, bless: bool) -> Self {
+        self.bless = bless;
+        self
+    }
+
+    /// Normalizes the actual output using `normalizer` before it is compared or written, instead of the normalizer
+    /// set by the `normalize` option of `#[fixtures]`.
+    pub fn normalize(mut self, normalizer: Normalizer) -> Self {
+        self.normalizer = Some(normalizer);
+        self
+    }
+
+    /// The path of the expected file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The path the actual output is written to on mismatch.
+    pub fn actual_path(&self) -> PathBuf {
+        match self
+            .path
+            .extension()
+            .and_then(|extension| extension.to_str())
+        {
+            Some(DEFAULT_EXTENSION) => self.path.with_extension("actual"),
+            Some(extension) => self.path.with_extension(format!("{extension}.actual")),
+            None => self.path.with_extension("actual"),
+        }
+    }
+
+    /// Compares `actual` with the expected file, or updates the expected file when blessing.
+    pub fn check(&self, actual: impl AsRef<str>) -> Result<(), GoldenError> {
+        let actual = match &self.normalizer {
+            Some(normalizer) => normalizer.normalize(actual.as_ref(), self.path.parent()),
+            None => actual.as_ref().to_string(),
+        };
+        let actual = actual.as_str();
+        let actual_path = self.actual_path();
+
+        if self.bless {
+            write(&self.path, actual)?;
+            return remove_if_exists(&actual_path);
+        }
+
+        let expected = match std::fs::read_to_string(&self.path) {
+            Ok(expected) => expected,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                write(&actual_path, actual)?;
+                return Err(GoldenError::Missing {
+                    path: self.path.clone(),
+                    actual_path,
+                });
+            }
+            Err(err) => {
+                return Err(GoldenError::Io {
+                    path: self.path.clone(),
+                    source: err,
+                })
+            }
+        };
+
+        if expected == actual {
+            return remove_if_exists(&actual_path);
+        }
+
+        write(&actual_path, actual)?;
+        let diff = TextDiff::from_lines(expected.as_str(), actual)
+            .unified_diff()
+            .header(
+                &self.path.display().to_string(),
+                &actual_path.display().to_string(),
+            )
+            .to_string();
+        Err(GoldenError::Mismatch {
+            path: self.path.clone(),
+            actual_path,
+            diff,
+        })
+    }
+
+    /// Compares `actual` with the expected file, panicking with a diff if they don't match.
+    #[track_caller]
+    pub fn assert(&self, actual: impl AsRef<str>) {
+        if let Err(err) = self.check(actual) {
+            panic!("{err}");
+        }
+    }
+}
+
+/// The error returned when the actual output doesn't match the expected file.
+#[derive(Debug)]
+pub enum GoldenError {
+    /// The expected file doesn't exist.
+    Missing { path: PathBuf, actual_path: PathBuf },
+    /// The actual output doesn't match the expected file.
+    Mismatch {
+        path: PathBuf,
+        actual_path: PathBuf,
+        /// A line-level unified diff from the expected to the actual output.
+        diff: String,
+    },
+    /// The expected or actual file couldn't be read or written.
+    Io { path: PathBuf, source: io::Error },
+}
+
+impl Display for GoldenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GoldenError::Missing { path, actual_path } => write!(
+                f,
+                "expected file `{}` doesn't exist (actual output written to `{}`)\n\
+                 hint: set {FIXTURES_BLESS}=1 to create it",
+                path.display(),
+                actual_path.display(),
+            ),
+            GoldenError::Mismatch {
+                path,
+                actual_path,
+                diff,
+            } => write!(
+                f,
+                "actual output doesn't match expected file `{}` (actual output written to `{}`)\n\n{diff}\n\
+                 hint: set {FIXTURES_BLESS}=1 to update it",
+                path.display(),
+                actual_path.display(),
+            ),
+            GoldenError::Io { path, source } => {
+                write!(f, "failed to access `{}`: {source}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for GoldenError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            GoldenError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), GoldenError> {
+    std::fs::write(path, contents).map_err(|err| GoldenError::Io {
+        path: path.to_path_buf(),
+        source: err,
+    })
+}
+
+fn remove_if_exists(path: &Path) -> Result<(), GoldenError> {
+    match std::fs::remove_file(path) {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => Err(GoldenError::Io {
+            path: path.to_path_buf(),
+            source: err,
+        }),
+        _ => Ok(()),
+    }
+}
@@ -1,2 +1,34 @@
 pub mod build;
+pub mod compare;
+mod embedded;
+mod expect_err;
+mod fixture;
+mod fixture_path;
+#[cfg(any(feature = "json", feature = "toml", feature = "yaml", feature = "ron"))]
+pub mod format;
+mod from_fixture;
+pub mod golden;
+mod markdown_block;
+pub mod normalize;
+pub mod rows;
+mod section;
+pub mod txtar;
+
+pub use embedded::{EmbeddedFixture, FromEmbedded};
+pub use fixture::Fixture;
+pub use fixture_path::FIXTURES_ROOT;
 pub use fixtures_proc::*;
+pub use from_fixture::FromFixture;
+pub use markdown_block::MarkdownBlock;
+pub use rows::Row;
+pub use section::Section;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::embedded::{from_embedded, LoadEmbedded, LoadFixture, Loader};
+    pub use crate::expect_err::expect_err;
+    pub use crate::fixture_path::fixture_path;
+    pub use crate::from_fixture::from_fixture;
+    pub use crate::normalize::{set_normalizer, with_normalizer};
+    pub use crate::rows::{from_row, DeserializeRow, LoadRow, RowLoader};
+}
@@ -0,0 +1,74 @@
+use std::path::Path;
+
+/// A fenced code block extracted from a Markdown file using the `markdown_blocks` option.
+///
+/// The file is read at compile time, so the contents of the block are embedded into the binary.
+///
+/// ````rs
+/// // Generates a test for each ```example block, paired with the ```output block which follows it
+/// #[fixtures(["spec/*.md"], markdown_blocks = "example")]
+/// #[test]
+/// fn test(block: fixtures::MarkdownBlock) {
+///     assert_eq!(render(block.contents()), block.output().unwrap());
+/// }
+/// ````
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MarkdownBlock {
+    path: &'static Path,
+    heading: Option<&'static str>,
+    info: &'static str,
+    line: usize,
+    contents: &'static str,
+    output: Option<&'static str>,
+}
+
+impl MarkdownBlock {
+    #[doc(hidden)]
+    pub fn new(
+        path: &'static Path,
+        heading: Option<&'static str>,
+        info: &'static str,
+        line: usize,
+        contents: &'static str,
+        output: Option<&'static str>,
+    ) -> Self {
+        Self {
+            path,
+            heading,
+            info,
+            line,
+            contents,
+            output,
+        }
+    }
+
+    /// The path of the Markdown file containing the block.
+    pub fn path(&self) -> &'static Path {
+        self.path
+    }
+
+    /// The text of the nearest heading before the block, if any.
+    pub fn heading(&self) -> Option<&'static str> {
+        self.heading
+    }
+
+    /// The info string of the block, e.g. `example rust` for a block opened with ```` ```example rust ````.
+    pub fn info(&self) -> &'static str {
+        self.info
+    }
+
+    /// The 1-based line number of the first line of the block's contents within the file.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The contents of the block, excluding the fences.
+    pub fn contents(&self) -> &'static str {
+        self.contents
+    }
+
+    /// The contents of the ```` ```output ```` block which directly follows the block, if any.
+    pub fn output(&self) -> Option<&'static str> {
+        self.output
+    }
+}
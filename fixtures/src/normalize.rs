@@ -0,0 +1,188 @@
+//! Normalization of test output before it is compared with, or written to, expected files.
+//!
+//! Output often contains absolute paths, temporary directories, timestamps or platform specific line endings, which
+//! make expected files non-portable. A [`Normalizer`] applies a pipeline of filters to remove these differences.
+//!
+//! ```rs
+//! fn normalizer() -> fixtures::normalize::Normalizer {
+//!     fixtures::normalize::Normalizer::standard().replace(r"\d+ms", "[DURATION]")
+//! }
+//!
+//! #[fixtures(["tests/fixtures/*.txt"], normalize = normalizer())]
+//! #[test]
+//! fn test(path: &std::path::Path) {
+//!     fixtures::golden::assert_golden(path, run(path));
+//! }
+//! ```
+//!
+//! The `normalize` option applies the normalizer to all golden assertions made by the test, unless they use their
+//! own normalizer (see [`Golden::normalize`](crate::golden::Golden::normalize)).
+
+use std::{
+    borrow::Cow,
+    cell::RefCell,
+    future::Future,
+    path::Path,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use regex::Regex;
+
+/// The placeholder which replaces the directory of the fixture.
+pub const DIR_PLACEHOLDER: &str = "$DIR";
+
+#[derive(Debug, Clone)]
+enum Filter {
+    FixtureDir,
+    Crlf,
+    TrailingWhitespace,
+    Replace(Regex, String),
+}
+
+/// A pipeline of filters which are applied to output, in the order they were added.
+#[derive(Debug, Clone, Default)]
+pub struct Normalizer {
+    filters: Vec<Filter>,
+}
+
+impl Normalizer {
+    /// A normalizer without any filters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A normalizer with all built-in filters: [`fixture_dir`](Self::fixture_dir), [`crlf`](Self::crlf) and
+    /// [`trailing_whitespace`](Self::trailing_whitespace).
+    pub fn standard() -> Self {
+        Self::new().fixture_dir().crlf().trailing_whitespace()
+    }
+
+    /// Replaces the directory containing the fixture with [`DIR_PLACEHOLDER`].
+    pub fn fixture_dir(mut self) -> Self {
+        self.filters.push(Filter::FixtureDir);
+        self
+    }
+
+    /// Replaces Windows line endings (`\r\n`) with `\n`.
+    pub fn crlf(mut self) -> Self {
+        self.filters.push(Filter::Crlf);
+        self
+    }
+
+    /// Removes whitespace from the end of each line.
+    pub fn trailing_whitespace(mut self) -> Self {
+        self.filters.push(Filter::TrailingWhitespace);
+        self
+    }
+
+    /// Replaces all matches of the regular expression `pattern` with `replacement`, which may refer to capture groups
+    /// (e.g. `$1`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pattern` isn't a valid regular expression.
+    #[track_caller]
+    pub fn replace(mut self, pattern: &str, replacement: impl Into<String>) -> Self {
+        let regex = match Regex::new(pattern) {
+            Ok(regex) => regex,
+            Err(err) => panic!("invalid normalization pattern `{pattern}`: {err}"),
+        };
+        self.filters
+            .push(Filter::Replace(regex, replacement.into()));
+        self
+    }
+
+    /// Applies the filters to `output`. `fixture_dir` is the directory replaced by the
+    /// [`fixture_dir`](Self::fixture_dir) filter.
+    pub fn normalize(&self, output: &str, fixture_dir: Option<&Path>) -> String {
+        let mut output = output.to_string();
+        for filter in &self.filters {
+            output = match filter {
+                Filter::FixtureDir => match fixture_dir {
+                    Some(fixture_dir) => replace_dir(&output, fixture_dir),
+                    None => output,
+                },
+                Filter::Crlf => output.replace("\r\n", "\n"),
+                Filter::TrailingWhitespace => output
+                    .split('\n')
+                    .map(str::trim_end)
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+                Filter::Replace(regex, replacement) => {
+                    match regex.replace_all(&output, replacement) {
+                        Cow::Borrowed(_) => output,
+                        Cow::Owned(replaced) => replaced,
+                    }
+                }
+            };
+        }
+        output
+    }
+}
+
+fn replace_dir(output: &str, dir: &Path) -> String {
+    let dir = dir.display().to_string();
+    if dir.is_empty() {
+        return output.to_string();
+    }
+    let output = output.replace(&dir, DIR_PLACEHOLDER);
+    // Paths may also be printed with forward slashes on Windows
+    let forward_slash_dir = dir.replace('\\', "/");
+    if forward_slash_dir != dir {
+        return output.replace(&forward_slash_dir, DIR_PLACEHOLDER);
+    }
+    output
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Normalizer>> = const { RefCell::new(None) };
+}
+
+/// Returns the normalizer set by the `normalize` option of the currently running test, if any.
+pub fn current() -> Option<Normalizer> {
+    CURRENT.with(|current| current.borrow().clone())
+}
+
+#[doc(hidden)]
+pub struct NormalizerGuard {
+    previous: Option<Normalizer>,
+}
+
+impl Drop for NormalizerGuard {
+    fn drop(&mut self) {
+        CURRENT.with(|current| *current.borrow_mut() = self.previous.take());
+    }
+}
+
+#[doc(hidden)]
+pub fn set_normalizer(normalizer: Normalizer) -> NormalizerGuard {
+    let previous = CURRENT.with(|current| current.borrow_mut().replace(normalizer));
+    NormalizerGuard { previous }
+}
+
+/// Makes `normalizer` the current normalizer while `future` is polled, rather than for as long as a guard is held, since
+/// the future may be polled on a different thread after each `.await`.
+#[doc(hidden)]
+pub fn with_normalizer<F: Future>(normalizer: Normalizer, future: F) -> WithNormalizer<F> {
+    WithNormalizer {
+        normalizer,
+        future: Box::pin(future),
+    }
+}
+
+#[doc(hidden)]
+pub struct WithNormalizer<F> {
+    normalizer: Normalizer,
+    future: Pin<Box<F>>,
+}
+
+impl<F: Future> Future for WithNormalizer<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let _normalizer = set_normalizer(this.normalizer.clone());
+        this.future.as_mut().poll(cx)
+    }
+}
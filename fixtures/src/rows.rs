@@ -0,0 +1,327 @@
+//! Table fixtures, which generate a test for each row of a CSV or JSON Lines file.
+//!
+//! ```rs
+//! #[fixtures(["tests/cases/*.csv"], rows = true, row_name = "name")]
+//! #[test]
+//! fn test((name, input, expected): (&str, &str, i64)) {
+//!     assert_eq!(eval(input), expected);
+//! }
+//! ```
+//!
+//! Each row is converted to the type of the fixture parameter using [`FromRow`], which is implemented for [`Row`],
+//! and for tuples of fields (see [`FromField`]). Types which don't implement it are deserialized using
+//! [`serde`](https://serde.rs), using the `csv` crate for CSV files (which requires the `csv` feature), and
+//! `serde_json` for JSON Lines files (which requires the `json` feature).
+
+use std::{convert::Infallible, fmt::Display, marker::PhantomData, path::Path, path::PathBuf};
+
+/// The format of the file a [`Row`] was read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RowFormat {
+    /// Comma-separated values, where the first record is the header.
+    Csv,
+    /// A JSON object or array on each line.
+    JsonLines,
+}
+
+/// A row of a CSV or JSON Lines file, which was read at compile time using the `rows` option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Row {
+    path: &'static Path,
+    format: RowFormat,
+    line: usize,
+    columns: &'static [&'static str],
+    fields: &'static [&'static str],
+    source: &'static str,
+}
+
+impl Row {
+    #[doc(hidden)]
+    pub fn new(
+        path: &'static Path,
+        format: RowFormat,
+        line: usize,
+        columns: &'static [&'static str],
+        fields: &'static [&'static str],
+        source: &'static str,
+    ) -> Self {
+        Self {
+            path,
+            format,
+            line,
+            columns,
+            fields,
+            source,
+        }
+    }
+
+    /// The path of the file containing the row.
+    pub fn path(&self) -> &'static Path {
+        self.path
+    }
+
+    /// The format of the file containing the row.
+    pub fn format(&self) -> RowFormat {
+        self.format
+    }
+
+    /// The 1-based line number the row starts on.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The names of the columns, from the header of a CSV file, or the keys of a JSON object. Rows of JSON arrays
+    /// don't have columns.
+    pub fn columns(&self) -> &'static [&'static str] {
+        self.columns
+    }
+
+    /// The fields of the row. For JSON Lines, strings are unquoted, and other values are formatted as JSON.
+    pub fn fields(&self) -> &'static [&'static str] {
+        self.fields
+    }
+
+    /// The field in the given column, if the row has it.
+    pub fn get(&self, column: &str) -> Option<&'static str> {
+        self.columns
+            .iter()
+            .position(|name| *name == column)
+            .map(|index| self.fields[index])
+    }
+
+    /// The text of the row, as it appears in the file.
+    pub fn source(&self) -> &'static str {
+        self.source
+    }
+}
+
+/// An error which occurred while converting a [`Row`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RowError {
+    message: String,
+}
+
+impl RowError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for RowError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for RowError {}
+
+/// Converts a [`Row`] to the value passed to a function decorated with `#[fixtures(..., rows = true)]`.
+pub trait FromRow: Sized {
+    /// The error returned when the row can't be converted.
+    type Error: Display;
+
+    fn from_row(row: Row) -> Result<Self, Self::Error>;
+}
+
+impl FromRow for Row {
+    type Error = Infallible;
+
+    fn from_row(row: Row) -> Result<Self, Self::Error> {
+        Ok(row)
+    }
+}
+
+/// Parses a single field of a [`Row`], for conversions to tuples.
+pub trait FromField: Sized {
+    fn from_field(field: &'static str) -> Result<Self, RowError>;
+}
+
+impl FromField for &'static str {
+    fn from_field(field: &'static str) -> Result<Self, RowError> {
+        Ok(field)
+    }
+}
+
+/// Empty fields (and `null` in JSON Lines) are parsed as `None`.
+impl<T: FromField> FromField for Option<T> {
+    fn from_field(field: &'static str) -> Result<Self, RowError> {
+        match field {
+            "" | "null" => Ok(None),
+            field => T::from_field(field).map(Some),
+        }
+    }
+}
+
+macro_rules! impl_from_field_for_from_str {
+    ($($ty:ty),*) => {
+        $(
+            impl FromField for $ty {
+                fn from_field(field: &'static str) -> Result<Self, RowError> {
+                    field.parse().map_err(|err| {
+                        RowError::new(format!(
+                            "failed to parse `{field}` as `{}`: {err}",
+                            stringify!($ty)
+                        ))
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_from_field_for_from_str!(
+    String, PathBuf, bool, char, f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128,
+    usize
+);
+
+macro_rules! impl_from_row_for_tuple {
+    ($len:literal => $($ty:ident),*) => {
+        impl<$($ty: FromField),*> FromRow for ($($ty,)*) {
+            type Error = RowError;
+
+            fn from_row(row: Row) -> Result<Self, Self::Error> {
+                let fields = row.fields();
+                if fields.len() != $len {
+                    return Err(RowError::new(format!(
+                        "expected {} fields, but the row has {}",
+                        $len,
+                        fields.len()
+                    )));
+                }
+                let mut fields = fields.iter();
+                Ok(($(
+                    $ty::from_field(fields.next().expect("field count should have been checked"))?,
+                )*))
+            }
+        }
+    };
+}
+
+impl_from_row_for_tuple!(1 => A);
+impl_from_row_for_tuple!(2 => A, B);
+impl_from_row_for_tuple!(3 => A, B, C);
+impl_from_row_for_tuple!(4 => A, B, C, D);
+impl_from_row_for_tuple!(5 => A, B, C, D, E);
+impl_from_row_for_tuple!(6 => A, B, C, D, E, F);
+impl_from_row_for_tuple!(7 => A, B, C, D, E, F, G);
+impl_from_row_for_tuple!(8 => A, B, C, D, E, F, G, H);
+
+#[doc(hidden)]
+#[track_caller]
+pub fn from_row<T: FromRow>(row: Row) -> T {
+    match T::from_row(row) {
+        Ok(value) => value,
+        Err(err) => panic!(
+            "failed to load row `{}:{}`: {err}",
+            row.path().display(),
+            row.line()
+        ),
+    }
+}
+
+/// Converts rows to the type of the fixture parameter. Types which implement [`FromRow`] are converted directly, and
+/// other types are deserialized using `Deserialized`, using autoref specialization.
+#[doc(hidden)]
+pub struct RowLoader<T>(PhantomData<T>);
+
+impl<T> RowLoader<T> {
+    #[allow(clippy::new_without_default)]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+#[doc(hidden)]
+pub trait LoadRow<T> {
+    fn load(&self, row: Row) -> T;
+}
+
+impl<T: FromRow> LoadRow<T> for RowLoader<T> {
+    #[track_caller]
+    fn load(&self, row: Row) -> T {
+        from_row(row)
+    }
+}
+
+#[doc(hidden)]
+pub trait DeserializeRow<T> {
+    fn load(&self, row: Row) -> T;
+}
+
+#[cfg(any(feature = "csv", feature = "json"))]
+impl<T: serde::de::DeserializeOwned> DeserializeRow<T> for &RowLoader<T> {
+    #[track_caller]
+    fn load(&self, row: Row) -> T {
+        from_row::<Deserialized<T>>(row).into_inner()
+    }
+}
+
+#[cfg(any(feature = "csv", feature = "json"))]
+pub use deserialize::Deserialized;
+
+#[cfg(any(feature = "csv", feature = "json"))]
+mod deserialize {
+    use std::ops::{Deref, DerefMut};
+
+    use serde::de::DeserializeOwned;
+
+    use super::{FromRow, Row, RowError, RowFormat};
+
+    /// A value deserialized from a [`Row`] using `serde`.
+    ///
+    /// The fields of CSV rows are parsed according to the type being deserialized, so numbers and booleans don't need
+    /// to be quoted.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub struct Deserialized<T>(pub T);
+
+    impl<T> Deserialized<T> {
+        pub fn into_inner(self) -> T {
+            self.0
+        }
+    }
+
+    impl<T> Deref for Deserialized<T> {
+        type Target = T;
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl<T> DerefMut for Deserialized<T> {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.0
+        }
+    }
+
+    impl<T: DeserializeOwned> FromRow for Deserialized<T> {
+        type Error = RowError;
+
+        fn from_row(row: Row) -> Result<Self, Self::Error> {
+            match row.format() {
+                #[cfg(feature = "csv")]
+                RowFormat::Csv => {
+                    let header = csv::StringRecord::from(row.columns().to_vec());
+                    csv::StringRecord::from(row.fields().to_vec())
+                        .deserialize(Some(&header))
+                        .map(Deserialized)
+                        .map_err(|err| RowError::new(err.to_string()))
+                }
+                #[cfg(feature = "json")]
+                RowFormat::JsonLines => serde_json::from_str(row.source())
+                    .map(Deserialized)
+                    .map_err(|err| RowError::new(err.to_string())),
+                #[allow(unreachable_patterns)]
+                format => Err(RowError::new(format!(
+                    "deserializing {format:?} rows requires the `{}` feature",
+                    match format {
+                        RowFormat::Csv => "csv",
+                        RowFormat::JsonLines => "json",
+                    }
+                ))),
+            }
+        }
+    }
+}
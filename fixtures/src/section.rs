@@ -0,0 +1,58 @@
+use std::path::Path;
+
+/// A section of a fixture file which was split into multiple tests using the `split` option.
+///
+/// The file is read at compile time, so the text of the section is embedded into the binary.
+///
+/// ```rs
+/// #[fixtures(["tests/corpus/*.txt"], split = regex("^=== (?<name>.*) ===$"))]
+/// #[test]
+/// fn test(section: fixtures::Section) {
+///     let result = parse(section.text());
+///     assert!(result.is_ok(), "{}:{}", section.path().display(), section.line());
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Section {
+    path: &'static Path,
+    name: Option<&'static str>,
+    line: usize,
+    text: &'static str,
+}
+
+impl Section {
+    #[doc(hidden)]
+    pub fn new(
+        path: &'static Path,
+        name: Option<&'static str>,
+        line: usize,
+        text: &'static str,
+    ) -> Self {
+        Self {
+            path,
+            name,
+            line,
+            text,
+        }
+    }
+
+    /// The path of the file containing the section.
+    pub fn path(&self) -> &'static Path {
+        self.path
+    }
+
+    /// The name captured from the delimiter which starts the section, if any.
+    pub fn name(&self) -> Option<&'static str> {
+        self.name
+    }
+
+    /// The 1-based line number of the first line of the section within the file.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The text of the section, excluding the delimiters.
+    pub fn text(&self) -> &'static str {
+        self.text
+    }
+}
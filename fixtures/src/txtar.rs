@@ -0,0 +1,239 @@
+//! Multi-file fixtures in the [txtar](https://pkg.go.dev/golang.org/x/tools/txtar) format.
+//!
+//! A txtar archive is a text file containing a comment, followed by any number of members, each of which starts with a
+//! `-- name --` line.
+//!
+//! ```txt
+//! Renames `foo` to `bar`.
+//! -- input/main.rs --
+//! fn foo() {}
+//! -- expected/main.rs --
+//! fn bar() {}
+//! ```
+//!
+//! Fixtures are parsed into an [`Archive`] using [`FromFixture`] (or [`FromEmbedded`]), so tests can take the archive
+//! as their parameter.
+//!
+//! ```rs
+//! #[fixtures(["tests/fixtures/*.txtar"])]
+//! #[test]
+//! fn test(archive: fixtures::txtar::Archive) {
+//!     let dir = archive.materialize().unwrap();
+//!     rename(dir.path().join("input"));
+//!     assert_eq!(
+//!         std::fs::read_to_string(dir.path().join("input/main.rs")).unwrap(),
+//!         archive.get("expected/main.rs").unwrap(),
+//!     );
+//! }
+//! ```
+
+use std::{
+    fmt::{self, Display},
+    io,
+    path::{Component, Path, PathBuf},
+    str::Utf8Error,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use crate::{EmbeddedFixture, FromEmbedded, FromFixture};
+
+/// A parsed txtar archive.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Archive {
+    comment: String,
+    members: Vec<Member>,
+}
+
+/// A named member of an [`Archive`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Member {
+    name: String,
+    contents: String,
+}
+
+impl Member {
+    /// The name of the member, which is a `/`-separated relative path.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The contents of the member, which end with a newline unless they're empty.
+    pub fn contents(&self) -> &str {
+        &self.contents
+    }
+}
+
+impl Archive {
+    /// Parses a txtar archive. Parsing can't fail, since any text before the first member is the comment.
+    pub fn parse(text: &str) -> Self {
+        let mut archive = Archive::default();
+        let mut current: Option<Member> = None;
+        for line in text.split_inclusive('\n') {
+            if let Some(name) = member_name(line) {
+                archive.members.extend(current.take());
+                current = Some(Member {
+                    name: name.to_string(),
+                    contents: String::new(),
+                });
+                continue;
+            }
+            match &mut current {
+                Some(member) => member.contents.push_str(line),
+                None => archive.comment.push_str(line),
+            }
+        }
+        archive.members.extend(current);
+        let texts = std::iter::once(&mut archive.comment).chain(
+            archive
+                .members
+                .iter_mut()
+                .map(|member| &mut member.contents),
+        );
+        for text in texts {
+            if !text.is_empty() && !text.ends_with('\n') {
+                text.push('\n');
+            }
+        }
+        archive
+    }
+
+    /// The text before the first member, which ends with a newline unless it's empty.
+    pub fn comment(&self) -> &str {
+        &self.comment
+    }
+
+    /// The members of the archive, in the order they appear.
+    pub fn members(&self) -> &[Member] {
+        &self.members
+    }
+
+    /// The names of the members of the archive, in the order they appear.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.members.iter().map(Member::name)
+    }
+
+    /// The contents of the first member with the given name, if any.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.members
+            .iter()
+            .find(|member| member.name == name)
+            .map(Member::contents)
+    }
+
+    /// Writes the members of the archive to the given directory, creating any parent directories.
+    ///
+    /// Returns an error if a member name is absolute, or contains `..`, since it would be written outside of `dir`.
+    /// Names are checked before anything is written.
+    pub fn write_to(&self, dir: &Path) -> io::Result<()> {
+        let paths = self
+            .members
+            .iter()
+            .map(|member| member_path(&member.name))
+            .collect::<io::Result<Vec<_>>>()?;
+        for (member, path) in self.members.iter().zip(paths) {
+            let path = dir.join(path);
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(path, &member.contents)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the members of the archive to a new temporary directory, which is removed when the returned [`TempDir`]
+    /// is dropped.
+    pub fn materialize(&self) -> io::Result<TempDir> {
+        let dir = TempDir::new()?;
+        self.write_to(dir.path())?;
+        Ok(dir)
+    }
+}
+
+impl Display for Archive {
+    /// Formats the archive as txtar, such that parsing the output returns the same archive.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.comment)?;
+        for member in &self.members {
+            writeln!(f, "-- {} --", member.name)?;
+            f.write_str(&member.contents)?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the name of the member started by a line like `-- name --`.
+fn member_name(line: &str) -> Option<&str> {
+    let line = line.strip_suffix('\n').unwrap_or(line);
+    let line = line.strip_suffix('\r').unwrap_or(line);
+    let name = line.strip_prefix("-- ")?.strip_suffix(" --")?.trim();
+    (!name.is_empty()).then_some(name)
+}
+
+/// Converts a member name to a relative path, rejecting names which would escape the directory.
+fn member_path(name: &str) -> io::Result<PathBuf> {
+    let path = Path::new(name);
+    if path
+        .components()
+        .all(|component| matches!(component, Component::Normal(_) | Component::CurDir))
+    {
+        Ok(path.to_path_buf())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("member `{name}` must be a relative path within the archive"),
+        ))
+    }
+}
+
+impl FromFixture for Archive {
+    type Error = io::Error;
+
+    fn from_fixture(path: &'static Path) -> Result<Self, Self::Error> {
+        std::fs::read_to_string(path).map(|text| Archive::parse(&text))
+    }
+}
+
+impl FromEmbedded for Archive {
+    type Error = Utf8Error;
+
+    fn from_embedded(fixture: EmbeddedFixture) -> Result<Self, Self::Error> {
+        fixture.contents_str().map(Archive::parse)
+    }
+}
+
+/// A temporary directory containing the members of an [`Archive`], which is removed when dropped.
+#[derive(Debug)]
+pub struct TempDir {
+    path: PathBuf,
+}
+
+impl TempDir {
+    fn new() -> io::Result<Self> {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let base = std::env::temp_dir();
+        loop {
+            let path = base.join(format!(
+                "fixtures-txtar-{}-{}",
+                std::process::id(),
+                COUNTER.fetch_add(1, Ordering::Relaxed)
+            ));
+            match std::fs::create_dir(&path) {
+                Ok(()) => return Ok(TempDir { path }),
+                // Left behind by an earlier process with the same id
+                Err(err) if err.kind() == io::ErrorKind::AlreadyExists => continue,
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    /// The path of the directory.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
+}
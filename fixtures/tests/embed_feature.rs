@@ -0,0 +1,107 @@
+//! Tests of invocations without the `embed` option when the `embed` feature is enabled. Cargo unifies features, so
+//! these expansions apply to every crate in the build once any crate enables the feature, and each parameter type must
+//! receive the same value as it does without the feature.
+
+use std::{
+    convert::Infallible,
+    fs::File,
+    io::Read as _,
+    path::{Path, PathBuf},
+};
+
+use fixtures::{fixtures, EmbeddedFixture, FromFixture};
+
+/// A type which only implements `FromFixture`.
+struct Lines(Vec<String>);
+
+impl FromFixture for Lines {
+    type Error = std::io::Error;
+
+    fn from_fixture(path: &'static Path) -> Result<Self, Self::Error> {
+        let contents = std::fs::read_to_string(path)?;
+        Ok(Lines(contents.lines().map(String::from).collect()))
+    }
+}
+
+/// A type which only implements `FromFixture`, and keeps the path it was loaded from.
+struct LoadedFrom(&'static Path);
+
+impl FromFixture for LoadedFrom {
+    type Error = Infallible;
+
+    fn from_fixture(path: &'static Path) -> Result<Self, Self::Error> {
+        Ok(LoadedFrom(path))
+    }
+}
+
+#[fixtures(["tests/fixtures/embed/*.txt"])]
+#[test]
+fn embeds_embedded_fixture(fixture: EmbeddedFixture) {
+    assert_eq!(fixture.path(), Path::new("tests/fixtures/embed/file_1.txt"));
+    assert_eq!(fixture.contents(), b"hello world!\n");
+}
+
+#[fixtures(["tests/fixtures/embed/*.txt"])]
+#[test]
+fn embeds_owned_contents(contents: String) {
+    assert_eq!(contents, "hello world!\n");
+}
+
+#[fixtures(["tests/fixtures/embed/*.txt"])]
+#[test]
+fn embeds_borrowed_contents(contents: &'static str) {
+    assert_eq!(contents, "hello world!\n");
+}
+
+#[fixtures(["tests/fixtures/embed/*.txt"])]
+#[test]
+fn passes_path_of_impl_trait(path: impl AsRef<Path>) {
+    assert!(path.as_ref().is_absolute());
+    assert!(path.as_ref().ends_with("tests/fixtures/embed/file_1.txt"));
+}
+
+#[fixtures(["tests/fixtures/embed/*.txt"])]
+#[test]
+fn loads_path_from_filesystem(path: &Path) {
+    assert!(path.is_absolute());
+    assert_eq!(std::fs::read_to_string(path).unwrap(), "hello world!\n");
+}
+
+#[fixtures(["tests/fixtures/embed/*.txt"])]
+#[test]
+fn loads_path_buf_from_filesystem(path: PathBuf) {
+    assert!(path.is_absolute());
+    assert_eq!(std::fs::read_to_string(path).unwrap(), "hello world!\n");
+}
+
+#[fixtures(["tests/fixtures/embed/*.txt"])]
+#[test]
+fn loads_file_from_filesystem(mut file: File) {
+    let mut contents = String::new();
+    file.read_to_string(&mut contents).unwrap();
+    assert_eq!(contents, "hello world!\n");
+}
+
+#[fixtures(["tests/fixtures/embed/*.txt"])]
+#[test]
+fn loads_from_fixture_type_from_filesystem(lines: Lines) {
+    assert_eq!(lines.0, ["hello world!"]);
+}
+
+#[fixtures(["tests/fixtures/embed/*.txt"])]
+#[test]
+fn passes_absolute_path_to_from_fixture_type(loaded_from: LoadedFrom) {
+    assert!(loaded_from.0.is_absolute());
+}
+
+#[fixtures(["tests/fixtures/embed/*.txt"], embed = false)]
+#[test]
+fn loads_from_filesystem_with_embed_false(contents: Vec<u8>) {
+    assert_eq!(contents, b"hello world!\n");
+}
+
+#[fixtures(["tests/fixtures/embed"], kind = "dir")]
+#[test]
+fn loads_directories_from_filesystem(path: &Path) {
+    assert!(path.is_dir());
+}
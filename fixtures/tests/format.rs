@@ -0,0 +1,90 @@
+use std::collections::BTreeMap;
+
+use fixtures::{
+    fixtures,
+    format::{Format, Json, Ron, Toml, Yaml},
+};
+
+type Case = BTreeMap<String, String>;
+
+#[fixtures(["tests/fixtures/format/malformed.json"])]
+fn json(_case: Json<Case>) {}
+
+#[fixtures(["tests/fixtures/format/malformed.toml"])]
+fn toml(_case: Toml<Case>) {}
+
+#[fixtures(["tests/fixtures/format/malformed.yaml"])]
+fn yaml(_case: Yaml<Case>) {}
+
+#[fixtures(["tests/fixtures/format/malformed.ron"])]
+fn ron(_case: Ron<Case>) {}
+
+fn panic_message(f: fn()) -> String {
+    let payload = std::panic::catch_unwind(f).expect_err("Expected the fixture to fail to load");
+    payload
+        .downcast_ref::<String>()
+        .cloned()
+        .expect("Expected a formatted panic message")
+}
+
+fn fixture_path(file_name: &str) -> String {
+    format!(
+        "{}/tests/fixtures/format/{file_name}",
+        env!("CARGO_MANIFEST_DIR")
+    )
+}
+
+#[test]
+fn reports_location_of_malformed_json() {
+    assert_eq!(
+        panic_message(json::EXPANSIONS[0]),
+        format!(
+            "failed to load fixture `{}`: failed to deserialize JSON at line 4, column 1: expected value",
+            fixture_path("malformed.json")
+        )
+    );
+}
+
+#[test]
+fn reports_location_of_malformed_toml() {
+    assert_eq!(
+        panic_message(toml::EXPANSIONS[0]),
+        format!(
+            "failed to load fixture `{}`: failed to deserialize TOML at line 2, column 5: string values must be quoted, expected literal string",
+            fixture_path("malformed.toml")
+        )
+    );
+}
+
+#[test]
+fn reports_location_of_malformed_yaml() {
+    assert_eq!(
+        panic_message(yaml::EXPANSIONS[0]),
+        format!(
+            "failed to load fixture `{}`: failed to deserialize YAML at line 2, column 4: c: invalid type: sequence, expected a string",
+            fixture_path("malformed.yaml")
+        )
+    );
+}
+
+#[test]
+fn reports_location_of_malformed_ron() {
+    assert_eq!(
+        panic_message(ron::EXPANSIONS[0]),
+        format!(
+            "failed to load fixture `{}`: failed to deserialize RON at line 3, column 6: Expected colon",
+            fixture_path("malformed.ron")
+        )
+    );
+}
+
+#[test]
+fn strips_location_from_parser_messages() {
+    let err = Format::Json
+        .deserialize::<Case>("{\n  \"a\": \n}")
+        .unwrap_err();
+
+    assert_eq!(err.message(), "expected value");
+    assert_eq!(err.line(), Some(3));
+    assert_eq!(err.column(), Some(1));
+}
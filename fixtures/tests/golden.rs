@@ -0,0 +1,85 @@
+use std::path::{Path, PathBuf};
+
+use fixtures::{
+    fixtures,
+    golden::{assert_golden, Golden, GoldenError},
+    normalize::Normalizer,
+};
+
+#[fixtures(["tests/fixtures/golden/*.txt"])]
+#[test]
+fn matches_expected_file(path: &Path) {
+    let input = std::fs::read_to_string(path).unwrap();
+
+    assert_golden(path, input.to_uppercase());
+}
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir()
+        .join("fixtures-golden-tests")
+        .join(format!("{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn reports_mismatch_with_diff() {
+    let dir = temp_dir("mismatch");
+    std::fs::write(dir.join("case.expected"), "a\nb\nc\n").unwrap();
+    let golden = Golden::new(dir.join("case.txt")).bless(false);
+
+    let err = golden.check("a\nx\nc\n").unwrap_err();
+
+    let GoldenError::Mismatch { diff, .. } = &err else {
+        panic!("Expected mismatch, got {err}");
+    };
+    assert!(diff.contains("-b\n"));
+    assert!(diff.contains("+x\n"));
+    assert_eq!(
+        std::fs::read_to_string(dir.join("case.actual")).unwrap(),
+        "a\nx\nc\n"
+    );
+}
+
+#[test]
+fn reports_missing_expected_file() {
+    let dir = temp_dir("missing");
+    let golden = Golden::new(dir.join("case.txt")).bless(false);
+
+    let err = golden.check("output").unwrap_err();
+
+    assert!(matches!(err, GoldenError::Missing { .. }));
+    assert!(dir.join("case.actual").exists());
+}
+
+#[test]
+fn blesses_expected_file() {
+    let dir = temp_dir("bless");
+    std::fs::write(dir.join("case.stdout"), "old").unwrap();
+    std::fs::write(dir.join("case.stdout.actual"), "new").unwrap();
+    let golden = Golden::new(dir.join("case.txt"))
+        .extension("stdout")
+        .bless(true);
+
+    golden.check("new").unwrap();
+
+    assert_eq!(
+        std::fs::read_to_string(dir.join("case.stdout")).unwrap(),
+        "new"
+    );
+    assert!(!dir.join("case.stdout.actual").exists());
+}
+
+#[test]
+fn normalizes_actual_output() {
+    let dir = temp_dir("normalize");
+    std::fs::write(dir.join("case.expected"), "error in $DIR/case.txt\n").unwrap();
+    let golden = Golden::new(dir.join("case.txt"))
+        .bless(false)
+        .normalize(Normalizer::standard());
+
+    golden
+        .check(format!("error in {}/case.txt  \r\n", dir.display()))
+        .unwrap();
+}
@@ -0,0 +1,75 @@
+use std::path::Path;
+
+use fixtures::{
+    fixtures,
+    normalize::{current, set_normalizer, Normalizer},
+};
+
+#[test]
+fn replaces_fixture_dir() {
+    let normalizer = Normalizer::new().fixture_dir();
+
+    let output = normalizer.normalize(
+        "error in /tmp/cases/input.txt",
+        Some(Path::new("/tmp/cases")),
+    );
+
+    assert_eq!(output, "error in $DIR/input.txt");
+}
+
+#[test]
+fn normalizes_line_endings_and_trailing_whitespace() {
+    let normalizer = Normalizer::new().crlf().trailing_whitespace();
+
+    let output = normalizer.normalize("a  \r\nb\t\r\n", None);
+
+    assert_eq!(output, "a\nb\n");
+}
+
+#[test]
+fn applies_replacements_in_order() {
+    let normalizer = Normalizer::new()
+        .replace(r"\d+ms", "[DURATION]")
+        .replace(r"took (\S+)", "elapsed $1");
+
+    let output = normalizer.normalize("took 125ms", None);
+
+    assert_eq!(output, "elapsed [DURATION]");
+}
+
+#[test]
+fn restores_previous_normalizer() {
+    assert!(current().is_none());
+    {
+        let _outer = set_normalizer(Normalizer::new().crlf());
+        {
+            let _inner = set_normalizer(Normalizer::standard());
+            assert!(current().is_some());
+        }
+        let normalizer = current().expect("Expected outer normalizer");
+        assert_eq!(normalizer.normalize("a \r\n", None), "a \n");
+    }
+    assert!(current().is_none());
+}
+
+#[fixtures(["tests/fixtures/normalize/*.txt"], normalize = Normalizer::new().replace(r"\d+ms", "[DURATION]"))]
+async fn normalized(_path: &Path) -> String {
+    for _ in 0..100 {
+        tokio::task::yield_now().await;
+    }
+    current()
+        .expect("Expected normalizer after yielding")
+        .normalize("took 125ms", None)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn keeps_normalizer_of_async_functions_across_threads() {
+    let handles = (0..16)
+        .map(|_| tokio::spawn(normalized::timing_dot_txt()))
+        .collect::<Vec<_>>();
+
+    for handle in handles {
+        assert_eq!(handle.await.unwrap(), "took [DURATION]");
+    }
+    assert!(current().is_none());
+}
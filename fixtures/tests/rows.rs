@@ -0,0 +1,115 @@
+use std::path::Path;
+
+use fixtures::{
+    fixtures,
+    rows::{Deserialized, FromRow, RowFormat},
+    Row,
+};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Case {
+    name: String,
+    input: String,
+    expected: i64,
+    note: Option<String>,
+}
+
+#[fixtures(["tests/fixtures/rows_deserialize/*"], rows = true, row_name = "name")]
+#[test]
+fn deserializes_rows(case: Case) {
+    let expected = match case.name.as_str() {
+        "plain" => Case {
+            name: "plain".to_string(),
+            input: "1 + 2".to_string(),
+            expected: 3,
+            note: None,
+        },
+        "quoted" => Case {
+            name: "quoted".to_string(),
+            input: "a, \"b\"".to_string(),
+            expected: -2,
+            note: Some("multi\r\nline".to_string()),
+        },
+        name => panic!("Unexpected row {name}"),
+    };
+    assert_eq!(case, expected);
+}
+
+fn csv_row(columns: &'static [&'static str], fields: &'static [&'static str]) -> Row {
+    Row::new(
+        Path::new("cases.csv"),
+        RowFormat::Csv,
+        2,
+        columns,
+        fields,
+        "",
+    )
+}
+
+fn error<T: FromRow>(row: Row) -> String {
+    match T::from_row(row) {
+        Ok(_) => panic!("Expected the row to fail to convert"),
+        Err(err) => err.to_string(),
+    }
+}
+
+#[test]
+fn reports_type_mismatch_in_csv_row() {
+    let row = csv_row(
+        &["name", "input", "expected", "note"],
+        &["a", "1 + 2", "three", ""],
+    );
+
+    assert_eq!(
+        error::<Deserialized<Case>>(row),
+        "CSV deserialize error: field 2: invalid digit found in string"
+    );
+}
+
+#[test]
+fn reports_missing_column_in_csv_row() {
+    let row = csv_row(&["name", "input", "note"], &["a", "1 + 2", ""]);
+
+    assert_eq!(
+        error::<Deserialized<Case>>(row),
+        "CSV deserialize error: missing field `expected`"
+    );
+}
+
+#[test]
+fn reports_wrong_number_of_fields_for_tuple() {
+    let row = csv_row(&["name", "input", "expected"], &["a", "1 + 2", "3"]);
+
+    assert_eq!(
+        error::<(&str, i64)>(row),
+        "expected 2 fields, but the row has 3"
+    );
+}
+
+#[test]
+fn reports_type_mismatch_for_tuple() {
+    let row = csv_row(&["name", "expected"], &["a", "three"]);
+
+    assert_eq!(
+        error::<(&str, i64)>(row),
+        "failed to parse `three` as `i64`: invalid digit found in string"
+    );
+}
+
+#[test]
+fn reports_type_mismatch_in_json_lines_row() {
+    let row = Row::new(
+        Path::new("cases.jsonl"),
+        RowFormat::JsonLines,
+        1,
+        &["name", "input", "expected", "note"],
+        &["a", "1 + 2", "three", "null"],
+        r#"{"name": "a", "input": "1 + 2", "expected": "three", "note": null}"#,
+    );
+
+    assert_eq!(
+        error::<Deserialized<Case>>(row),
+        "invalid type: string \"three\", expected i64 at line 1 column 51"
+    );
+}
@@ -2,8 +2,13 @@ use indoc::indoc;
 use std::process::Command;
 
 fn test_example_expansion(example_name: &str) {
+    test_example_expansion_with_features(example_name, &[]);
+}
+
+fn test_example_expansion_with_features(example_name: &str, features: &[&str]) {
     let output = Command::new("cargo")
         .args(["expand", "--tests", "--example", example_name])
+        .args(["--features", &features.join(",")])
         .output()
         .expect("failed to expand example");
 
@@ -35,11 +40,31 @@ fn test_example_expansion(example_name: &str) {
     insta::assert_snapshot!(example_name, expansion);
 }
 
+#[test]
+fn async_fns() {
+    test_example_expansion("async_fns");
+}
+
 #[test]
 fn basic_usage() {
     test_example_expansion("basic_usage");
 }
 
+#[test]
+fn collisions() {
+    test_example_expansion("collisions");
+}
+
+#[test]
+fn companions() {
+    test_example_expansion("companions");
+}
+
+#[test]
+fn compare() {
+    test_example_expansion("compare");
+}
+
 #[test]
 fn complex_legacy_ignore_config() {
     test_example_expansion("complex_legacy_ignore_config");
@@ -55,6 +80,31 @@ fn criterion() {
     test_example_expansion("criterion");
 }
 
+#[test]
+fn deserialize() {
+    test_example_expansion_with_features("deserialize", &["json", "toml"]);
+}
+
+#[test]
+fn embed() {
+    test_example_expansion("embed");
+}
+
+#[test]
+fn expect_err() {
+    test_example_expansion("expect_err");
+}
+
+#[test]
+fn from_fixture() {
+    test_example_expansion("from_fixture");
+}
+
+#[test]
+fn generic_signatures() {
+    test_example_expansion("generic_signatures");
+}
+
 #[test]
 fn ignore_globs() {
     test_example_expansion("ignore_globs");
@@ -65,22 +115,97 @@ fn ignore_attributes() {
     test_example_expansion("ignore_attributes");
 }
 
+#[test]
+fn inject_contents() {
+    test_example_expansion("inject_contents");
+}
+
 #[test]
 fn invalid_identifiers() {
     test_example_expansion("invalid_identifiers");
 }
 
+#[test]
+fn kind() {
+    test_example_expansion("kind");
+}
+
 #[test]
 fn legacy_ignore_globs() {
     test_example_expansion("legacy_ignore_globs");
 }
 
+#[test]
+fn markdown_blocks() {
+    test_example_expansion("markdown_blocks");
+}
+
 #[test]
 fn multiple_fixtures() {
     test_example_expansion("multiple_fixtures");
 }
 
+#[test]
+fn name_template() {
+    test_example_expansion("name_template");
+}
+
 #[test]
 fn negative_globs() {
     test_example_expansion("negative_globs");
 }
+
+#[test]
+fn nested() {
+    test_example_expansion("nested");
+}
+
+#[test]
+fn normalize() {
+    test_example_expansion("normalize");
+}
+
+#[test]
+fn path_style() {
+    test_example_expansion("path_style");
+}
+
+#[test]
+fn product() {
+    test_example_expansion("product");
+}
+
+#[test]
+fn root() {
+    test_example_expansion("root");
+}
+
+#[test]
+fn rows() {
+    test_example_expansion("rows");
+}
+
+#[test]
+fn should_panic() {
+    test_example_expansion("should_panic");
+}
+
+#[test]
+fn split() {
+    test_example_expansion("split");
+}
+
+#[test]
+fn txtar() {
+    test_example_expansion("txtar");
+}
+
+#[test]
+fn values() {
+    test_example_expansion("values");
+}
+
+#[test]
+fn test_attrs() {
+    test_example_expansion("test_attrs");
+}
@@ -0,0 +1,131 @@
+use std::path::PathBuf;
+
+use fixtures::txtar::Archive;
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir()
+        .join("fixtures-txtar-tests")
+        .join(format!("{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn parses_comment_and_members() {
+    let archive = Archive::parse("Renames `foo`.\n\n-- a.rs --\nfn foo() {}\n-- dir/b.rs --\n");
+
+    assert_eq!(archive.comment(), "Renames `foo`.\n\n");
+    assert_eq!(archive.names().collect::<Vec<_>>(), ["a.rs", "dir/b.rs"]);
+    assert_eq!(archive.get("a.rs"), Some("fn foo() {}\n"));
+    assert_eq!(archive.get("dir/b.rs"), Some(""));
+    assert_eq!(archive.get("c.rs"), None);
+}
+
+#[test]
+fn parses_archive_without_comment() {
+    let archive = Archive::parse("-- a.txt --\na\n");
+
+    assert_eq!(archive.comment(), "");
+    assert_eq!(archive.get("a.txt"), Some("a\n"));
+}
+
+#[test]
+fn parses_marker_lines_only_as_members() {
+    let archive = Archive::parse("-- not a member\n--  --\n-- a.txt --\n-- b.txt -- x\n");
+
+    assert_eq!(archive.comment(), "-- not a member\n--  --\n");
+    assert_eq!(archive.names().collect::<Vec<_>>(), ["a.txt"]);
+    assert_eq!(archive.get("a.txt"), Some("-- b.txt -- x\n"));
+}
+
+#[test]
+fn adds_trailing_newline_to_last_member() {
+    let archive = Archive::parse("-- a.txt --\na\n-- b.txt --\nb");
+
+    assert_eq!(archive.get("a.txt"), Some("a\n"));
+    assert_eq!(archive.get("b.txt"), Some("b\n"));
+}
+
+#[test]
+fn parses_crlf_marker_lines() {
+    let archive = Archive::parse("comment\r\n-- a.txt --\r\na\r\n");
+
+    assert_eq!(archive.comment(), "comment\r\n");
+    assert_eq!(archive.get("a.txt"), Some("a\r\n"));
+}
+
+#[test]
+fn round_trips_through_display() {
+    for text in [
+        "",
+        "comment\n",
+        "comment\n-- a.txt --\na\n-- b/c.txt --\n",
+        "-- a.txt --\n\n\n-- b.txt --\nb\n",
+    ] {
+        let archive = Archive::parse(text);
+
+        assert_eq!(archive.to_string(), text);
+        assert_eq!(Archive::parse(&archive.to_string()), archive);
+    }
+}
+
+#[test]
+fn round_trips_through_display_without_trailing_newlines() {
+    let archive = Archive::parse("comment-- x --\n-- a.txt --\na");
+
+    assert_eq!(archive.to_string(), "comment-- x --\n-- a.txt --\na\n");
+    assert_eq!(Archive::parse(&archive.to_string()), archive);
+
+    let archive = Archive::parse("comment");
+
+    assert_eq!(archive.to_string(), "comment\n");
+    assert_eq!(Archive::parse(&archive.to_string()), archive);
+}
+
+#[test]
+fn writes_members_to_directory() {
+    let dir = temp_dir("write");
+    let archive = Archive::parse("-- a.txt --\na\n-- b/c.txt --\nc\n");
+
+    archive.write_to(&dir).unwrap();
+
+    assert_eq!(std::fs::read_to_string(dir.join("a.txt")).unwrap(), "a\n");
+    assert_eq!(std::fs::read_to_string(dir.join("b/c.txt")).unwrap(), "c\n");
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn materializes_members_in_temporary_directory() {
+    let archive = Archive::parse("-- a/b.txt --\nb\n");
+
+    let dir = archive.materialize().unwrap();
+    let path = dir.path().to_path_buf();
+
+    assert_eq!(
+        std::fs::read_to_string(path.join("a/b.txt")).unwrap(),
+        "b\n"
+    );
+    drop(dir);
+    assert!(!path.exists());
+}
+
+#[test]
+fn rejects_members_outside_of_directory() {
+    for name in ["../x", "/etc/x", "a/../../x"] {
+        let dir = temp_dir("reject");
+        let archive = Archive::parse(&format!("-- ok.txt --\nok\n-- {name} --\nx\n"));
+
+        let err = archive.write_to(&dir.join("out")).unwrap_err();
+
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput, "{name}");
+        assert_eq!(
+            err.to_string(),
+            format!("member `{name}` must be a relative path within the archive")
+        );
+        // Nothing is written, including the valid member before the invalid one
+        assert!(!dir.join("out").exists(), "{name}");
+        assert!(!dir.join("x").exists(), "{name}");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
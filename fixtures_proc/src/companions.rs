@@ -0,0 +1,98 @@
+use std::path::{Path, PathBuf};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::LitStr;
+
+use crate::{
+    fixture_path::FixturePath,
+    parse::{companions::Companions, path_style::PathStyleKind, spanned::Spanned},
+};
+
+/// Returns the path of the companion file with the given extension, which shares the stem of `path`.
+fn companion_path(path: &Path, extension: &str) -> PathBuf {
+    path.with_extension(extension)
+}
+
+/// Checks whether `path` has the extension of one of the companions, in which case it isn't treated as a fixture in
+/// its own right when it is matched by the glob patterns.
+pub fn is_companion(path: &Path, companions: &Companions) -> bool {
+    let Some(extension) = path.extension() else {
+        return false;
+    };
+    companions
+        .companions()
+        .iter()
+        .any(|companion| extension == companion.extension())
+}
+
+/// Returns an expression of type `::fixtures::Fixture`, or an error naming the fixture if a required companion file
+/// is missing.
+pub fn fixture_tokens(
+    path: &Path,
+    fixture_path: &FixturePath,
+    companions: &Companions,
+    path_style: PathStyleKind,
+) -> syn::Result<TokenStream> {
+    let mut companion_tokens = Vec::new();
+    let mut include_paths = Vec::new();
+    for companion in companions.companions() {
+        let extension = companion.extension();
+        let companion_path = companion_path(path, extension);
+        if !companion_path.is_file() {
+            if companion.required() {
+                return Err(syn::Error::new(
+                    companion.span(),
+                    format!(
+                        "The fixture '{}' is missing the required companion file '{}'",
+                        path.display(),
+                        companion_path.display()
+                    ),
+                ));
+            }
+            continue;
+        }
+        let extension = LitStr::new(extension, companion.span());
+        let companion_path = FixturePath::new(&companion_path, path_style, companion.span())?;
+        include_paths.push(companion_path.include_tokens());
+        let companion_path = companion_path.path_tokens();
+        companion_tokens.push(quote!((#extension, #companion_path)));
+    }
+    let path = fixture_path.path_tokens();
+    // Companion files are only read at runtime, so they are included to let cargo rebuild the tests when they change
+    Ok(quote! {{
+        #(const _: &[u8] = ::std::include_bytes!(#include_paths);)*
+        ::fixtures::Fixture::new(#path, ::std::vec::Vec::from([#(#companion_tokens),*]))
+    }})
+}
+
+#[cfg(test)]
+mod tests {
+    use proc_macro2::Span;
+
+    use super::*;
+
+    #[test]
+    fn correctly_identifies_companions() {
+        let companions: Companions =
+            syn::parse_str(r#"["stderr", "args?"]"#).expect("Failed to parse companions");
+
+        assert!(is_companion(Path::new("ui/test.stderr"), &companions));
+        assert!(is_companion(Path::new("ui/test.args"), &companions));
+        assert!(!is_companion(Path::new("ui/test.rs"), &companions));
+        assert!(!is_companion(Path::new("ui/stderr"), &companions));
+    }
+
+    #[test]
+    fn returns_error_on_missing_required_companion() {
+        let companions: Companions =
+            syn::parse_str(r#"["missing"]"#).expect("Failed to parse companions");
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+        let fixture_path = FixturePath::new(&path, PathStyleKind::Absolute, Span::call_site())
+            .expect("Failed to create fixture path");
+
+        let result = fixture_tokens(&path, &fixture_path, &companions, PathStyleKind::Absolute);
+
+        assert!(result.is_err());
+    }
+}
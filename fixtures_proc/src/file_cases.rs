@@ -0,0 +1,209 @@
+use std::path::Path;
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{LitBool, LitStr, Type};
+
+use crate::{
+    file_name_to_valid_identifier,
+    fixture_path::FixturePath,
+    identifiers, markdown,
+    parse::{spanned::Spanned, split::Split},
+    rows::{self, RowsFormat},
+    sections, unique_idents,
+};
+
+/// How a fixture file is divided into multiple tests.
+pub enum CaseSource<'a> {
+    /// `split = "..."` - a test for each section of the file.
+    Split(&'a Split),
+    /// `markdown_blocks = "..."` - a test for each tagged code block of a Markdown file.
+    MarkdownBlocks(&'a LitStr),
+    /// `rows = true` - a test for each row of a CSV or JSON Lines file, optionally named after the `row_name` column.
+    /// `ty` is the type of the fixture parameter, which each row is converted to.
+    Rows {
+        rows: &'a LitBool,
+        row_name: Option<&'a LitStr>,
+        ty: Option<&'a Type>,
+    },
+}
+
+/// A test generated for part of a fixture file.
+struct FileCase {
+    /// The name of the case, if it has one. Unnamed cases are numbered instead.
+    name: Option<String>,
+    /// The 1-based line number the case starts on.
+    line: usize,
+    /// The expression passed to the decorated function.
+    fixture_arg_expr: TokenStream,
+}
+
+impl CaseSource<'_> {
+    /// The name of the option which selected this source.
+    pub fn option_name(&self) -> &'static str {
+        match self {
+            CaseSource::Split(_) => "split",
+            CaseSource::MarkdownBlocks(_) => "markdown_blocks",
+            CaseSource::Rows { .. } => "rows",
+        }
+    }
+
+    /// The prefix of the names of unnamed cases, e.g. `section` for `section_1`.
+    fn unnamed_prefix(&self) -> &'static str {
+        match self {
+            CaseSource::Split(_) => "section",
+            CaseSource::MarkdownBlocks(_) => "block",
+            CaseSource::Rows { .. } => "row",
+        }
+    }
+
+    fn cases(
+        &self,
+        path: &Path,
+        relative_path: &str,
+        contents: &str,
+        path_tokens: &TokenStream,
+    ) -> syn::Result<Vec<FileCase>> {
+        match self {
+            CaseSource::Split(split) => Ok(sections::split(contents, split.delimiter())
+                .into_iter()
+                .map(|section| FileCase {
+                    fixture_arg_expr: section.to_tokens(path_tokens),
+                    name: section.name,
+                    line: section.line,
+                })
+                .collect()),
+            CaseSource::MarkdownBlocks(tag) => Ok(markdown::blocks(contents, &tag.value())
+                .into_iter()
+                .map(|block| FileCase {
+                    fixture_arg_expr: block.to_tokens(path_tokens),
+                    // Headings are prose, so they're lowercased to produce snake case identifiers
+                    name: block
+                        .heading
+                        .filter(|heading| !heading.is_empty())
+                        .map(|heading| heading.to_lowercase()),
+                    line: block.line,
+                })
+                .collect()),
+            CaseSource::Rows { row_name, ty, .. } => {
+                let Some(format) = RowsFormat::from_path(path) else {
+                    return Err(syn::Error::new(
+                        self.span(),
+                        format!(
+                            "The rows of '{relative_path}' can't be read. Only '.csv', '.jsonl' and '.ndjson' files are supported."
+                        ),
+                    ));
+                };
+                let rows = rows::parse(contents, format).map_err(|err| {
+                    syn::Error::new(
+                        self.span(),
+                        format!("{relative_path}:{}: {}", err.line, err.message),
+                    )
+                })?;
+                rows.into_iter()
+                    .map(|row| {
+                        let name = match row_name {
+                            Some(row_name) => match row.field(&row_name.value()) {
+                                Some(name) => {
+                                    Some(name.to_string()).filter(|name| !name.is_empty())
+                                }
+                                None => {
+                                    return Err(syn::Error::new(
+                                        row_name.span(),
+                                        format!(
+                                        "{relative_path}:{}: The row doesn't have a '{}' column",
+                                        row.line,
+                                        row_name.value()
+                                    ),
+                                    ))
+                                }
+                            },
+                            None => None,
+                        };
+                        if let Some(ty) = ty {
+                            row.check_fields(ty).map_err(|message| {
+                                syn::Error::new(
+                                    self.span(),
+                                    format!("{relative_path}:{}: {message}", row.line),
+                                )
+                            })?;
+                        }
+                        Ok(FileCase {
+                            fixture_arg_expr: row.to_tokens(path_tokens, format, *ty),
+                            name,
+                            line: row.line,
+                        })
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+impl Spanned for CaseSource<'_> {
+    fn span(&self) -> Span {
+        match self {
+            CaseSource::Split(split) => split.span(),
+            CaseSource::MarkdownBlocks(tag) => tag.span(),
+            CaseSource::Rows { rows, .. } => rows.span(),
+        }
+    }
+}
+
+/// Reads the file at `path` and divides it into cases, returning the name and fixture argument of each case, and an
+/// item which includes the file, so the crate is recompiled when it changes.
+pub fn file_cases(
+    path: &Path,
+    fixture_path: &FixturePath,
+    source: &CaseSource,
+    relative_path: String,
+) -> syn::Result<(Vec<(String, TokenStream)>, TokenStream)> {
+    if path.is_dir() {
+        return Err(syn::Error::new(
+            source.span(),
+            format!(
+                "The directory '{}' can't be used with the {} option. Only files can be divided into cases.",
+                path.display(),
+                source.option_name(),
+            ),
+        ));
+    }
+    let contents = std::fs::read_to_string(path).map_err(|err| {
+        syn::Error::new(
+            source.span(),
+            format!("Failed to read '{}': {err}", path.display()),
+        )
+    })?;
+    let cases = source.cases(path, &relative_path, &contents, &fixture_path.path_tokens())?;
+
+    let sanitized_names = cases
+        .iter()
+        .enumerate()
+        .map(|(index, case)| match &case.name {
+            Some(name) => file_name_to_valid_identifier(name),
+            None => format!("{}_{}", source.unnamed_prefix(), index + 1),
+        })
+        .collect::<Vec<_>>();
+    let candidates = sanitized_names
+        .iter()
+        .map(|sanitized_name| identifiers::Candidate {
+            module: String::new(),
+            ident: identifiers::shorten(sanitized_name.clone()),
+            relative_path: relative_path.clone(),
+        })
+        .collect::<Vec<_>>();
+    let idents = unique_idents(&candidates, &sanitized_names, source.span(), |index| {
+        format!("{relative_path}:{}", cases[index].line)
+    });
+
+    let include_path = fixture_path.include_tokens();
+    let include_tokens = quote! {
+        const _: &[u8] = ::std::include_bytes!(#include_path);
+    };
+    let cases = idents
+        .into_iter()
+        .zip(cases)
+        .map(|(ident, case)| (ident, case.fixture_arg_expr))
+        .collect();
+    Ok((cases, include_tokens))
+}
@@ -0,0 +1,283 @@
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{
+    parse_quote, spanned::Spanned, visit_mut::VisitMut, FnArg, GenericArgument, Lifetime, LitStr,
+    PathArguments, Type,
+};
+
+use crate::{
+    fixture_path::FixturePath,
+    parse::format::{Format, FormatKind},
+};
+
+/// Describes whether a fixture is embedded into the binary.
+#[derive(Debug, Clone, Copy)]
+pub enum Embed<'a> {
+    /// The fixture is loaded from the filesystem at runtime.
+    No,
+    /// `embed = true` - the fixture is embedded, and the parameter type must implement `::fixtures::FromEmbedded`.
+    Required(&'a LitStr),
+    /// The `embed` feature - the fixture is embedded if the parameter type implements `::fixtures::FromEmbedded`,
+    /// and loaded using `::fixtures::FromFixture` otherwise.
+    Preferred(&'a LitStr),
+}
+
+/// Describes how a fixture is passed to the first parameter of the decorated function.
+#[derive(Debug, PartialEq, Eq)]
+pub enum FixtureArg {
+    /// `impl Trait` - passes `::std::path::Path::new(path)`.
+    Path,
+    /// Any other type - passes the value loaded by `<T as ::fixtures::FromFixture>::from_fixture(path)`.
+    FromFixture(Box<Type>),
+    /// `format = "..."` - passes the value deserialized using the given `::fixtures::format` wrapper type.
+    Deserialize(Box<Type>),
+    /// `&'static str` - passes `include_str!(path)`.
+    Str,
+    /// `&'static [u8]` - passes `include_bytes!(path)`.
+    Bytes,
+    /// `String` - passes an owned copy of `include_str!(path)`.
+    String,
+    /// `Vec<u8>` - passes an owned copy of `include_bytes!(path)`.
+    VecU8,
+}
+
+impl FixtureArg {
+    pub fn from_fn_arg(fn_arg: Option<&FnArg>, format: Option<&Format>) -> Self {
+        match (fn_arg, format) {
+            (Some(FnArg::Typed(pat_ty)), Some(format)) => {
+                Self::deserialize(&pat_ty.ty, format.kind())
+            }
+            (Some(FnArg::Typed(pat_ty)), None) => Self::from_type(&pat_ty.ty),
+            _ => FixtureArg::Path,
+        }
+    }
+
+    pub fn deserialize(ty: &Type, format: FormatKind) -> Self {
+        let ty = erase_lifetimes(ty);
+        FixtureArg::Deserialize(Box::new(match format {
+            FormatKind::Auto => parse_quote!(::fixtures::format::Deserialized<#ty>),
+            FormatKind::Json => parse_quote!(::fixtures::format::Json<#ty>),
+            FormatKind::Toml => parse_quote!(::fixtures::format::Toml<#ty>),
+            FormatKind::Yaml => parse_quote!(::fixtures::format::Yaml<#ty>),
+            FormatKind::Ron => parse_quote!(::fixtures::format::Ron<#ty>),
+        }))
+    }
+
+    pub fn from_type(ty: &Type) -> Self {
+        match ty {
+            Type::Reference(reference) if reference.mutability.is_none() => {
+                match reference.elem.as_ref() {
+                    elem if type_is(elem, "str") => FixtureArg::Str,
+                    Type::Slice(slice) if type_is(&slice.elem, "u8") => FixtureArg::Bytes,
+                    _ => FixtureArg::FromFixture(Box::new(erase_lifetimes(ty))),
+                }
+            }
+            Type::ImplTrait(_) => FixtureArg::Path,
+            ty if type_is(ty, "String") => FixtureArg::String,
+            ty if type_is_vec_u8(ty) => FixtureArg::VecU8,
+            ty => FixtureArg::FromFixture(Box::new(erase_lifetimes(ty))),
+        }
+    }
+
+    /// Whether the parameter is a `fixtures::Fixture`, which is the only type that can receive companion files.
+    pub fn is_fixture(&self) -> bool {
+        matches!(self, FixtureArg::FromFixture(ty) if type_is(ty, "Fixture"))
+    }
+
+    /// Returns the expression passed to the decorated function. Embedded fixtures are loaded using
+    /// `::fixtures::FromEmbedded` rather than `::fixtures::FromFixture`.
+    pub fn to_tokens(&self, fixture_path: &FixturePath, embed: Embed) -> TokenStream {
+        let include_path = fixture_path.include_tokens();
+        let embedded_fixture = |embedded_path: &LitStr| {
+            quote! {
+                ::fixtures::EmbeddedFixture::new(
+                    #embedded_path,
+                    ::std::include_bytes!(#include_path),
+                )
+            }
+        };
+        match (self, embed) {
+            // Spanned at the type, so that types which don't implement `FromEmbedded` are reported there
+            (FixtureArg::FromFixture(ty), Embed::Required(embedded_path)) => {
+                let embedded_fixture = embedded_fixture(embedded_path);
+                quote_spanned! {ty.span()=>
+                    ::fixtures::__private::from_embedded::<#ty>(#embedded_fixture)
+                }
+            }
+            // Types which don't implement `FromEmbedded`, such as `&Path`, fall back to `FromFixture`
+            (FixtureArg::FromFixture(ty), Embed::Preferred(embedded_path)) => {
+                let embedded_fixture = embedded_fixture(embedded_path);
+                let path = fixture_path.path_tokens();
+                quote! {{
+                    use ::fixtures::__private::{LoadEmbedded as _, LoadFixture as _};
+                    (&::fixtures::__private::Loader::<#ty>::new()).load(#embedded_fixture, || #path)
+                }}
+            }
+            (
+                FixtureArg::Deserialize(ty),
+                Embed::Required(embedded_path) | Embed::Preferred(embedded_path),
+            ) => {
+                let embedded_fixture = embedded_fixture(embedded_path);
+                quote! {
+                    ::fixtures::__private::from_embedded::<#ty>(#embedded_fixture).into_inner()
+                }
+            }
+            _ => self.to_path_tokens(fixture_path),
+        }
+    }
+
+    fn to_path_tokens(&self, fixture_path: &FixturePath) -> TokenStream {
+        let include_path = fixture_path.include_tokens();
+        let path = fixture_path.path_tokens();
+        match self {
+            FixtureArg::Path => path,
+            FixtureArg::FromFixture(ty) => quote! {
+                ::fixtures::__private::from_fixture::<#ty>(#path)
+            },
+            FixtureArg::Deserialize(ty) => quote! {
+                ::fixtures::__private::from_fixture::<#ty>(#path).into_inner()
+            },
+            FixtureArg::Str => quote!(::std::include_str!(#include_path)),
+            FixtureArg::Bytes => quote!(::std::include_bytes!(#include_path)),
+            FixtureArg::String => {
+                quote!(::std::string::String::from(
+                    ::std::include_str!(#include_path)
+                ))
+            }
+            FixtureArg::VecU8 => quote!(::std::include_bytes!(#include_path).to_vec()),
+        }
+    }
+}
+
+/// Replaces named lifetimes with `'_`, since they aren't declared within the generated functions.
+fn erase_lifetimes(ty: &Type) -> Type {
+    struct EraseLifetimes;
+
+    impl VisitMut for EraseLifetimes {
+        fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
+            if lifetime.ident != "static" {
+                *lifetime = Lifetime::new("'_", lifetime.span());
+            }
+        }
+    }
+
+    let mut ty = ty.clone();
+    EraseLifetimes.visit_type_mut(&mut ty);
+    ty
+}
+
+/// Checks whether the type is a path without generic arguments, ending in `ident`.
+fn type_is(ty: &Type, ident: &str) -> bool {
+    let Type::Path(type_path) = ty else {
+        return false;
+    };
+    if type_path.qself.is_some() {
+        return false;
+    }
+    let Some(segment) = type_path.path.segments.last() else {
+        return false;
+    };
+    segment.ident == ident && segment.arguments.is_none()
+}
+
+fn type_is_vec_u8(ty: &Type) -> bool {
+    let Type::Path(type_path) = ty else {
+        return false;
+    };
+    if type_path.qself.is_some() {
+        return false;
+    }
+    let Some(segment) = type_path.path.segments.last() else {
+        return false;
+    };
+    if segment.ident != "Vec" {
+        return false;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return false;
+    };
+    if args.args.len() != 1 {
+        return false;
+    }
+    matches!(args.args.first(), Some(GenericArgument::Type(ty)) if type_is(ty, "u8"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture_arg(ty: &str) -> FixtureArg {
+        FixtureArg::from_type(&syn::parse_str(ty).expect("Failed to parse type"))
+    }
+
+    #[test]
+    fn correctly_detects_path() {
+        assert_eq!(fixture_arg("impl AsRef<Path>"), FixtureArg::Path);
+    }
+
+    #[test]
+    fn correctly_detects_from_fixture() {
+        assert_eq!(
+            fixture_arg("&Path"),
+            FixtureArg::FromFixture(Box::new(syn::parse_str("&Path").unwrap()))
+        );
+        assert_eq!(
+            fixture_arg("&mut str"),
+            FixtureArg::FromFixture(Box::new(syn::parse_str("&mut str").unwrap()))
+        );
+        assert_eq!(
+            fixture_arg("std::fs::File"),
+            FixtureArg::FromFixture(Box::new(syn::parse_str("std::fs::File").unwrap()))
+        );
+        assert_eq!(
+            fixture_arg("&'a Path"),
+            FixtureArg::FromFixture(Box::new(syn::parse_str("&'_ Path").unwrap()))
+        );
+    }
+
+    #[test]
+    fn correctly_wraps_deserialized_types() {
+        let ty = syn::parse_str("Case").unwrap();
+
+        assert_eq!(
+            FixtureArg::deserialize(&ty, FormatKind::Auto),
+            FixtureArg::Deserialize(Box::new(
+                syn::parse_str("::fixtures::format::Deserialized<Case>").unwrap()
+            ))
+        );
+        assert_eq!(
+            FixtureArg::deserialize(&ty, FormatKind::Toml),
+            FixtureArg::Deserialize(Box::new(
+                syn::parse_str("::fixtures::format::Toml<Case>").unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn correctly_detects_str() {
+        assert_eq!(fixture_arg("&str"), FixtureArg::Str);
+        assert_eq!(fixture_arg("&'static str"), FixtureArg::Str);
+    }
+
+    #[test]
+    fn correctly_detects_bytes() {
+        assert_eq!(fixture_arg("&[u8]"), FixtureArg::Bytes);
+        assert_eq!(fixture_arg("&'static [u8]"), FixtureArg::Bytes);
+    }
+
+    #[test]
+    fn correctly_detects_string() {
+        assert_eq!(fixture_arg("String"), FixtureArg::String);
+        assert_eq!(fixture_arg("std::string::String"), FixtureArg::String);
+    }
+
+    #[test]
+    fn correctly_detects_vec_u8() {
+        assert_eq!(fixture_arg("Vec<u8>"), FixtureArg::VecU8);
+        assert_eq!(fixture_arg("::std::vec::Vec<u8>"), FixtureArg::VecU8);
+        assert_eq!(
+            fixture_arg("Vec<u16>"),
+            FixtureArg::FromFixture(Box::new(syn::parse_str("Vec<u16>").unwrap()))
+        );
+    }
+}
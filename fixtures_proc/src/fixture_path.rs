@@ -0,0 +1,146 @@
+use std::path::{Component, Path};
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::LitStr;
+
+use crate::{parse::path_style::PathStyleKind, root_dir::manifest_dir};
+
+/// The path of a fixture file, and how it is emitted in the generated code.
+pub struct FixturePath {
+    style: PathStyleKind,
+    absolute: LitStr,
+    /// The path relative to `CARGO_MANIFEST_DIR`, using `/` as the separator.
+    manifest_relative: LitStr,
+}
+
+impl FixturePath {
+    pub fn new(path: &Path, style: PathStyleKind, span: Span) -> syn::Result<Self> {
+        let absolute = path.to_str().expect("file path should be valid UTF-8");
+        let manifest_relative = match style {
+            PathStyleKind::Absolute => String::new(),
+            PathStyleKind::Manifest | PathStyleKind::Env => {
+                relative_path(path, &manifest_dir()).ok_or_else(|| {
+                    syn::Error::new(
+                        span,
+                        format!(
+                            "The fixture '{absolute}' can't be expressed relative to CARGO_MANIFEST_DIR"
+                        ),
+                    )
+                })?
+            }
+        };
+        Ok(FixturePath {
+            style,
+            absolute: LitStr::new(absolute, span),
+            manifest_relative: LitStr::new(&manifest_relative, span),
+        })
+    }
+
+    /// Returns an expression of type `&'static str`, which can be passed to `include_str!` and `include_bytes!`.
+    pub fn include_tokens(&self) -> TokenStream {
+        let FixturePath {
+            absolute,
+            manifest_relative,
+            ..
+        } = self;
+        match self.style {
+            PathStyleKind::Absolute => quote!(#absolute),
+            PathStyleKind::Manifest | PathStyleKind::Env => quote! {
+                ::std::concat!(::std::env!("CARGO_MANIFEST_DIR"), "/", #manifest_relative)
+            },
+        }
+    }
+
+    /// Returns an expression of type `&'static ::std::path::Path`.
+    pub fn path_tokens(&self) -> TokenStream {
+        match self.style {
+            PathStyleKind::Absolute | PathStyleKind::Manifest => {
+                let include_tokens = self.include_tokens();
+                quote!(::std::path::Path::new(#include_tokens))
+            }
+            PathStyleKind::Env => {
+                let manifest_relative = &self.manifest_relative;
+                quote! {{
+                    static PATH: ::std::sync::OnceLock<::std::path::PathBuf> = ::std::sync::OnceLock::new();
+                    ::fixtures::__private::fixture_path(&PATH, #manifest_relative)
+                }}
+            }
+        }
+    }
+}
+
+/// Returns `path` relative to `base`, using `/` as the separator. Both paths must be absolute.
+fn relative_path(path: &Path, base: &Path) -> Option<String> {
+    let mut path_components = path.components().peekable();
+    let mut base_components = base.components().peekable();
+
+    while let (Some(path_component), Some(base_component)) =
+        (path_components.peek(), base_components.peek())
+    {
+        if path_component != base_component {
+            break;
+        }
+        path_components.next();
+        base_components.next();
+    }
+
+    let mut segments = Vec::new();
+    for component in base_components {
+        match component {
+            Component::Normal(_) => segments.push(".."),
+            Component::CurDir => {}
+            _ => return None,
+        }
+    }
+    for component in path_components {
+        match component {
+            Component::Normal(segment) => segments.push(segment.to_str()?),
+            Component::CurDir => {}
+            _ => return None,
+        }
+    }
+
+    Some(segments.join("/"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn correctly_computes_nested_relative_path() {
+        assert_eq!(
+            relative_path(
+                Path::new("/repo/crate/tests/fixtures/file.txt"),
+                Path::new("/repo/crate")
+            ),
+            Some("tests/fixtures/file.txt".to_string())
+        );
+    }
+
+    #[test]
+    fn emits_no_build_path_in_env_style() {
+        let manifest_dir = manifest_dir();
+        let path = manifest_dir.join("tests").join("fixtures").join("file.txt");
+        let fixture_path = FixturePath::new(&path, PathStyleKind::Env, Span::call_site())
+            .expect("Failed to build fixture path");
+
+        let tokens = fixture_path.path_tokens().to_string();
+
+        assert!(tokens.contains(r#""tests/fixtures/file.txt""#), "{tokens}");
+        assert!(!tokens.contains(manifest_dir.to_str().unwrap()), "{tokens}");
+        assert!(!tokens.contains("CARGO_MANIFEST_DIR"), "{tokens}");
+    }
+
+    #[test]
+    fn correctly_computes_sibling_relative_path() {
+        assert_eq!(
+            relative_path(
+                Path::new("/repo/fixtures/file.txt"),
+                Path::new("/repo/crate")
+            ),
+            Some("../fixtures/file.txt".to_string())
+        );
+    }
+}
@@ -0,0 +1,195 @@
+use std::path::{Component, Path};
+
+use regex::Regex;
+use syn::LitStr;
+
+/// Matches fixture paths against the glob patterns which found them, to extract the text matched by each wildcard.
+pub struct GlobCaptures {
+    regexes: Vec<Regex>,
+}
+
+impl GlobCaptures {
+    /// Builds a matcher for the (non-negated) glob patterns, which are relative to the root directory.
+    pub fn new(globs: &[LitStr]) -> syn::Result<Self> {
+        let mut regexes = Vec::with_capacity(globs.len());
+        for glob in globs {
+            let glob_str = glob.value();
+            if glob_str.starts_with('!') {
+                continue;
+            }
+            let regex = Regex::new(&glob_to_regex(&glob_str)).map_err(|err| {
+                syn::Error::new(
+                    glob.span(),
+                    format!("Failed to extract the captures of the glob pattern: {err}"),
+                )
+            })?;
+            regexes.push(regex);
+        }
+        Ok(GlobCaptures { regexes })
+    }
+
+    /// Returns the text matched by each wildcard of the first glob pattern which matches `relative_path`. Wildcards
+    /// which didn't match anything (e.g. `**/` matching no directories) capture an empty string.
+    pub fn captures(&self, relative_path: &Path) -> Option<Vec<String>> {
+        let relative_path = relative_path
+            .components()
+            .filter_map(|component| match component {
+                Component::Normal(name) => name.to_str(),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join("/");
+        self.regexes.iter().find_map(|regex| {
+            let captures = regex.captures(&relative_path)?;
+            Some(
+                captures
+                    .iter()
+                    .skip(1)
+                    .map(|capture| capture.map_or("", |capture| capture.as_str()).to_string())
+                    .collect(),
+            )
+        })
+    }
+}
+
+/// Converts a glob pattern to a regular expression with a capture group for each wildcard. Like the gitignore-style
+/// matching of globwalk, a pattern without a `/` matches at any depth, and a leading `/` anchors it to the root.
+fn glob_to_regex(glob: &str) -> String {
+    let mut regex = String::from("^");
+    let glob = match glob.strip_prefix('/') {
+        Some(anchored) => anchored,
+        None => {
+            if !glob.contains('/') {
+                regex.push_str("(?:.*/)?");
+            }
+            glob
+        }
+    };
+    let chars = glob.chars().collect::<Vec<_>>();
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '*' if chars.get(i + 1) == Some(&'*') => {
+                // `**/` matches any number of directories, including none
+                if chars.get(i + 2) == Some(&'/') {
+                    regex.push_str("(?:(.*)/)?");
+                    i += 3;
+                } else {
+                    regex.push_str("(.*)");
+                    i += 2;
+                }
+                continue;
+            }
+            '*' => regex.push_str("([^/]*)"),
+            '?' => regex.push_str("([^/])"),
+            '[' => {
+                let mut end = i + 1;
+                if matches!(chars.get(end), Some('!' | '^')) {
+                    end += 1;
+                }
+                // A `]` directly after the opening bracket is part of the class
+                if chars.get(end) == Some(&']') {
+                    end += 1;
+                }
+                while end < chars.len() && chars[end] != ']' {
+                    end += 1;
+                }
+                if end == chars.len() {
+                    regex.push_str(&regex::escape("["));
+                } else {
+                    let mut class = chars[i + 1..end].iter().collect::<String>();
+                    if class.starts_with('!') {
+                        class.replace_range(..1, "^");
+                    }
+                    regex.push_str(&format!("([{}])", class.replace('\\', "\\\\")));
+                    i = end;
+                }
+            }
+            '{' => match chars[i..].iter().position(|c| *c == '}') {
+                Some(len) => {
+                    let alternatives = chars[i + 1..i + len]
+                        .iter()
+                        .collect::<String>()
+                        .split(',')
+                        .map(regex::escape)
+                        .collect::<Vec<_>>();
+                    regex.push_str(&format!("({})", alternatives.join("|")));
+                    i += len;
+                }
+                None => regex.push_str(&regex::escape("{")),
+            },
+            '\\' if i + 1 < chars.len() => {
+                i += 1;
+                regex.push_str(&regex::escape(&chars[i].to_string()));
+            }
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+        i += 1;
+    }
+    regex.push('$');
+    regex
+}
+
+#[cfg(test)]
+mod tests {
+    use proc_macro2::Span;
+
+    use super::*;
+
+    fn captures(glob: &str, path: &str) -> Option<Vec<String>> {
+        GlobCaptures::new(&[LitStr::new(glob, Span::call_site())])
+            .expect("Failed to build glob captures")
+            .captures(Path::new(path))
+    }
+
+    #[test]
+    fn correctly_captures_wildcards() {
+        assert_eq!(
+            captures("fixtures/*/case_?.txt", "fixtures/parser/case_a.txt"),
+            Some(vec!["parser".to_string(), "a".to_string()])
+        );
+        assert_eq!(
+            captures("fixtures/**/*.{txt,md}", "fixtures/a/b/c.md"),
+            Some(vec!["a/b".to_string(), "c".to_string(), "md".to_string()])
+        );
+        assert_eq!(
+            captures("fixtures/**/*.txt", "fixtures/c.txt"),
+            Some(vec!["".to_string(), "c".to_string()])
+        );
+        assert_eq!(
+            captures("fixtures/[!x]*.txt", "fixtures/abc.txt"),
+            Some(vec!["a".to_string(), "bc".to_string()])
+        );
+    }
+
+    #[test]
+    fn correctly_captures_wildcards_of_nested_matches() {
+        assert_eq!(
+            captures("*.txt", "fixtures/a/b.txt"),
+            Some(vec!["b".to_string()])
+        );
+        assert_eq!(captures("*.txt", "b.txt"), Some(vec!["b".to_string()]));
+        assert_eq!(captures("/*.txt", "fixtures/b.txt"), None);
+        assert_eq!(captures("/*.txt", "b.txt"), Some(vec!["b".to_string()]));
+    }
+
+    #[test]
+    fn returns_none_for_unmatched_paths() {
+        assert_eq!(captures("fixtures/*.txt", "fixtures/a/b.txt"), None);
+        assert_eq!(captures("fixtures/*.txt", "other/b.txt"), None);
+    }
+
+    #[test]
+    fn ignores_negated_globs() {
+        let glob_captures = GlobCaptures::new(&[
+            LitStr::new("!fixtures/*.txt", Span::call_site()),
+            LitStr::new("fixtures/*.txt", Span::call_site()),
+        ])
+        .expect("Failed to build glob captures");
+
+        assert_eq!(
+            glob_captures.captures(Path::new("fixtures/a.txt")),
+            Some(vec!["a".to_string()])
+        );
+    }
+}
@@ -0,0 +1,159 @@
+use std::collections::{BTreeMap, HashSet};
+
+/// The maximum length, in characters, of a generated test identifier. Longer identifiers are truncated and suffixed
+/// with a hash of the full identifier, so they remain unique and stable.
+const MAX_IDENT_LEN: usize = 64;
+
+/// The test identifier chosen for a fixture, before collisions are resolved.
+pub struct Candidate {
+    /// The module the test is generated in, relative to the module named after the function.
+    pub module: String,
+    pub ident: String,
+    /// The path of the fixture relative to the root directory, which is used to order colliding fixtures.
+    pub relative_path: String,
+}
+
+/// Truncates identifiers longer than [`MAX_IDENT_LEN`], replacing the truncated part with a hash of the identifier.
+pub fn shorten(ident: String) -> String {
+    if ident.chars().count() <= MAX_IDENT_LEN {
+        return ident;
+    }
+    let hash = format!("{:016x}", fnv1a(ident.as_bytes()));
+    let prefix = ident
+        .chars()
+        .take(MAX_IDENT_LEN - hash.len() - 1)
+        .collect::<String>();
+    format!("{prefix}_{hash}")
+}
+
+/// Returns a unique identifier for each candidate, in the same order. Candidates with colliding identifiers are
+/// ordered by their relative path. The first keeps its identifier, and the others are suffixed with `_2`, `_3`, etc.
+pub fn disambiguate(candidates: &[Candidate]) -> Vec<String> {
+    let mut idents = candidates
+        .iter()
+        .map(|candidate| candidate.ident.clone())
+        .collect::<Vec<_>>();
+    let mut taken = candidates
+        .iter()
+        .map(|candidate| (candidate.module.as_str(), candidate.ident.clone()))
+        .collect::<HashSet<_>>();
+
+    let mut collisions: BTreeMap<(&str, &str), Vec<usize>> = BTreeMap::new();
+    for (index, candidate) in candidates.iter().enumerate() {
+        collisions
+            .entry((&candidate.module, &candidate.ident))
+            .or_default()
+            .push(index);
+    }
+    for ((module, ident), mut indices) in collisions {
+        indices.sort_by(|a, b| {
+            candidates[*a]
+                .relative_path
+                .cmp(&candidates[*b].relative_path)
+        });
+        let mut suffix = 1;
+        for index in indices.into_iter().skip(1) {
+            let unique_ident = loop {
+                suffix += 1;
+                let unique_ident = format!("{ident}_{suffix}");
+                if !taken.contains(&(module, unique_ident.clone())) {
+                    break unique_ident;
+                }
+            };
+            taken.insert((module, unique_ident.clone()));
+            idents[index] = unique_ident;
+        }
+    }
+
+    idents
+}
+
+/// Returns the first pair of candidates with the same identifier, if any.
+pub fn find_collision(candidates: &[Candidate]) -> Option<(&Candidate, &Candidate)> {
+    let mut seen = std::collections::HashMap::new();
+    for candidate in candidates {
+        if let Some(other) = seen.insert((&candidate.module, &candidate.ident), candidate) {
+            return Some((other, candidate));
+        }
+    }
+    None
+}
+
+/// The 64 bit FNV-1a hash, which unlike the hashers of the standard library is guaranteed to be stable.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(module: &str, ident: &str, relative_path: &str) -> Candidate {
+        Candidate {
+            module: module.to_string(),
+            ident: ident.to_string(),
+            relative_path: relative_path.to_string(),
+        }
+    }
+
+    #[test]
+    fn correctly_disambiguates_by_relative_path() {
+        let idents = disambiguate(&[
+            candidate("", "a_b_dot_txt", "b/a_b.txt"),
+            candidate("", "a_b_dot_txt", "a/a-b.txt"),
+            candidate("", "c_dot_txt", "c.txt"),
+        ]);
+
+        assert_eq!(idents, ["a_b_dot_txt_2", "a_b_dot_txt", "c_dot_txt"]);
+    }
+
+    #[test]
+    fn does_not_disambiguate_across_modules() {
+        let idents = disambiguate(&[
+            candidate("a", "x_dot_txt", "a/x.txt"),
+            candidate("b", "x_dot_txt", "b/x.txt"),
+        ]);
+
+        assert_eq!(idents, ["x_dot_txt", "x_dot_txt"]);
+    }
+
+    #[test]
+    fn skips_suffixes_which_are_already_taken() {
+        let idents = disambiguate(&[
+            candidate("", "a", "a"),
+            candidate("", "a", "b/a"),
+            candidate("", "a_2", "c/a_2"),
+        ]);
+
+        assert_eq!(idents, ["a", "a_3", "a_2"]);
+    }
+
+    #[test]
+    fn correctly_shortens_long_identifiers() {
+        let short = "a".repeat(MAX_IDENT_LEN);
+        assert_eq!(shorten(short.clone()), short);
+
+        let long = "a".repeat(MAX_IDENT_LEN + 1);
+        let shortened = shorten(long.clone());
+        assert_eq!(shortened.chars().count(), MAX_IDENT_LEN);
+        assert_eq!(shortened, shorten(long));
+        assert_ne!(shortened, shorten("a".repeat(MAX_IDENT_LEN + 2)));
+    }
+
+    #[test]
+    fn correctly_finds_collisions() {
+        let candidates = [
+            candidate("", "a", "x/a.txt"),
+            candidate("", "b", "b.txt"),
+            candidate("", "a", "y/a.txt"),
+        ];
+
+        let (first, second) = find_collision(&candidates).expect("Expected a collision");
+
+        assert_eq!(first.relative_path, "x/a.txt");
+        assert_eq!(second.relative_path, "y/a.txt");
+        assert!(find_collision(&candidates[..2]).is_none());
+    }
+}
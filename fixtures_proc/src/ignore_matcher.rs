@@ -5,23 +5,24 @@ use proc_macro2::Span;
 use syn::LitStr;
 
 use crate::parse::{
-    ignore_attribute::IgnoreAttribute, legacy_ignore_config::LegacyIgnoreConfig,
+    glob_attribute::{GlobAttribute, GlobAttributeKind, IgnoreAttribute},
+    legacy_ignore_config::LegacyIgnoreConfig,
     spanned::Spanned as _,
 };
 
 struct IgnoreGlob<'config> {
     matcher: GlobMatcher,
-    reason: Option<&'config LitStr>,
+    value: Option<&'config LitStr>,
 }
 
 pub struct IgnoreMatcher<'config> {
     globs: Vec<IgnoreGlob<'config>>,
-    default_reason: Option<&'config LitStr>,
+    default_value: Option<&'config LitStr>,
 }
 
 #[derive(Debug)]
 pub enum MatchResult<'config> {
-    Matched { reason: Option<&'config LitStr> },
+    Matched { value: Option<&'config LitStr> },
     Unmatched,
 }
 
@@ -29,17 +30,14 @@ impl<'config> IgnoreMatcher<'config> {
     pub fn new<P: AsRef<Path>>(
         legacy_config: &'config Option<LegacyIgnoreConfig>,
         ignore_args: &'config [IgnoreAttribute],
-        current_dir: P,
+        root_dir: P,
     ) -> Result<Self, (Span, globset::Error)> {
         let globs = ignore_args.iter().map(|attr| {
-            let full_path = current_dir.as_ref().join(attr.args.paths.value());
-            match Glob::new(full_path.to_str().expect("expected UTF-8")) {
-                Ok(glob) => Ok(IgnoreGlob {
-                    matcher: glob.compile_matcher(),
-                    reason: attr.args.reason.as_ref(),
-                }),
-                Err(err) => Err((attr.args.paths.span(), err)),
-            }
+            IgnoreGlob::new(
+                root_dir.as_ref(),
+                &attr.args.paths,
+                attr.args.value.as_ref(),
+            )
         });
 
         let globs = if let Some(legacy_config) = legacy_config {
@@ -48,14 +46,8 @@ impl<'config> IgnoreMatcher<'config> {
                 .paths()
                 .iter()
                 .map(|path| {
-                    let full_path = current_dir.as_ref().join(path.path().value());
-                    match Glob::new(full_path.to_str().expect("expected UTF-8")) {
-                        Ok(glob) => Ok(IgnoreGlob {
-                            matcher: glob.compile_matcher(),
-                            reason: path.reason().as_ref(),
-                        }),
-                        Err(err) => Err((path.span(), err)),
-                    }
+                    IgnoreGlob::new(root_dir.as_ref(), path.path(), path.reason().as_ref())
+                        .map_err(|(_, err)| (path.span(), err))
                 })
                 .chain(globs)
                 .collect::<Result<Vec<_>, _>>()?
@@ -65,9 +57,49 @@ impl<'config> IgnoreMatcher<'config> {
 
         Ok(IgnoreMatcher {
             globs,
-            default_reason: legacy_config.as_ref().and_then(|cfg| cfg.reason().as_ref()),
+            default_value: legacy_config.as_ref().and_then(|cfg| cfg.reason().as_ref()),
         })
     }
+
+    /// Matches the globs of `#[fixtures::should_panic]` or `#[fixtures::expect_err]` attributes, where the value of
+    /// a match is the expected panic message or the regex which the error must match.
+    pub fn for_attributes<K: GlobAttributeKind, P: AsRef<Path>>(
+        attrs: &'config [GlobAttribute<K>],
+        root_dir: P,
+    ) -> Result<Self, (Span, globset::Error)> {
+        let globs = attrs
+            .iter()
+            .map(|attr| {
+                IgnoreGlob::new(
+                    root_dir.as_ref(),
+                    &attr.args.paths,
+                    attr.args.value.as_ref(),
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(IgnoreMatcher {
+            globs,
+            default_value: None,
+        })
+    }
+}
+
+impl<'config> IgnoreGlob<'config> {
+    fn new(
+        root_dir: &Path,
+        glob: &LitStr,
+        value: Option<&'config LitStr>,
+    ) -> Result<Self, (Span, globset::Error)> {
+        let full_path = root_dir.join(glob.value());
+        match Glob::new(full_path.to_str().expect("expected UTF-8")) {
+            Ok(matcher) => Ok(IgnoreGlob {
+                matcher: matcher.compile_matcher(),
+                value,
+            }),
+            Err(err) => Err((glob.span(), err)),
+        }
+    }
 }
 
 impl IgnoreMatcher<'_> {
@@ -79,10 +111,10 @@ impl IgnoreMatcher<'_> {
         for glob in &self.globs {
             if glob.matcher.is_match(&path) {
                 return MatchResult::Matched {
-                    reason: glob
-                        .reason
+                    value: glob
+                        .value
                         .as_ref()
-                        .or(self.default_reason.as_ref())
+                        .or(self.default_value.as_ref())
                         .map(|v| &**v),
                 };
             }
@@ -1,34 +1,309 @@
 extern crate proc_macro;
 
+mod companions;
+mod file_cases;
+mod fixture_arg;
+mod fixture_path;
+mod glob_captures;
+mod identifiers;
 mod ignore_matcher;
+mod markdown;
+mod nested;
 mod parse;
+mod root_dir;
+mod rows;
+mod sections;
+mod signature;
+mod test_attribute;
 mod utils;
 
+use file_cases::CaseSource;
+use fixture_arg::{Embed, FixtureArg};
+use fixture_path::FixturePath;
+use glob_captures::GlobCaptures;
 use ignore_matcher::{IgnoreMatcher, MatchResult};
-use parse::{ignore_attribute::IgnoreAttribute, spanned::Spanned};
+use parse::{
+    args::Args,
+    companions::Companions,
+    format::Format,
+    glob_attribute::{ExpectErrAttribute, IgnoreAttribute, ShouldPanicAttribute},
+    kind::FixtureKind,
+    name_template::{NameContext, NameTemplate},
+    path_style::PathStyleKind,
+    paths::Paths,
+    root::RootKind,
+    spanned::Spanned,
+    values::ValueList,
+};
 use proc_macro::TokenStream;
 use proc_macro_error2::{emit_warning, proc_macro_error};
 use quote::quote;
 use syn::{
-    parse_macro_input, parse_quote, punctuated::Punctuated, AttrStyle, FnArg, Ident, ItemFn,
-    LitStr, Meta, Pat, Path, Token,
+    parse_macro_input, parse_quote, punctuated::Punctuated, Attribute, FnArg, Ident, ItemFn,
+    LitBool, LitStr, Pat, ReturnType, Signature, Token,
 };
+use unicode_normalization::UnicodeNormalization as _;
 use unicode_xid::UnicodeXID;
 
+/// A further list of paths, whose fixtures are bound to one of the parameters after the first.
+struct ProductSet {
+    /// The name of each fixture, as used within the names of combinations.
+    names: Vec<String>,
+    relative_paths: Vec<String>,
+    fixture_arg_exprs: Vec<proc_macro2::TokenStream>,
+    fixture_attrs: Vec<FixtureAttrs>,
+}
+
+/// The attributes added to the tests of a fixture by `#[fixtures::ignore]` and `#[fixtures::should_panic]`, and
+/// whether `#[fixtures::expect_err]` expects them to return an error.
+#[derive(Clone, Default)]
+struct FixtureAttrs {
+    ignore: proc_macro2::TokenStream,
+    should_panic: proc_macro2::TokenStream,
+    /// The `matches` argument of `::fixtures::__private::expect_err`, if the tests are expected to return an error.
+    expect_err: Option<proc_macro2::TokenStream>,
+}
+
+/// A test for a fixture (or case, or combination of fixtures), and one combination of the values of the `values` option.
+struct ValueCase {
+    module_path: Vec<Ident>,
+    /// The name of the test, before it's made unique.
+    name: String,
+    /// Describes the fixture and values of the test, for warnings about renamed tests.
+    description: String,
+    fixture_arg_expr: proc_macro2::TokenStream,
+    remaining_arg_exprs: Vec<proc_macro2::TokenStream>,
+    fixture_attrs: FixtureAttrs,
+}
+
 struct TestFnExpansion {
+    /// The modules the expansion is nested in, relative to the module named after the function.
+    module_path: Vec<Ident>,
     ident: Ident,
+    /// The block evaluated by the expansion.
+    body: proc_macro2::TokenStream,
     tokens: proc_macro2::TokenStream,
 }
 
+/// A test for a fixture (or case, or combination of fixtures), before it's bound to the values of the `values` option.
+struct Case {
+    module_path: Vec<Ident>,
+    ident: Ident,
+    fixture_arg_expr: proc_macro2::TokenStream,
+    fixture_attrs: FixtureAttrs,
+}
+
+/// The attributes of the decorated function, with the attributes handled by `#[fixtures]` separated from the rest.
+struct FnAttrs<'a> {
+    /// The attributes which are copied to each expansion.
+    other: Vec<&'a Attribute>,
+    ignore: Vec<IgnoreAttribute>,
+    should_panic: Vec<ShouldPanicAttribute>,
+    expect_err: Vec<ExpectErrAttribute>,
+}
+
+impl<'a> FnAttrs<'a> {
+    fn new(attrs: &'a [Attribute]) -> syn::Result<Self> {
+        let mut fn_attrs = FnAttrs {
+            other: Vec::new(),
+            ignore: Vec::new(),
+            should_panic: Vec::new(),
+            expect_err: Vec::new(),
+        };
+        for attr in attrs {
+            if let Some(ignore_attr) = IgnoreAttribute::try_from_attribute(attr)? {
+                fn_attrs.ignore.push(ignore_attr);
+            } else if let Some(should_panic_attr) = ShouldPanicAttribute::try_from_attribute(attr)?
+            {
+                fn_attrs.should_panic.push(should_panic_attr);
+            } else if let Some(expect_err_attr) = ExpectErrAttribute::try_from_attribute(attr)? {
+                fn_attrs.expect_err.push(expect_err_attr);
+            } else {
+                fn_attrs.other.push(attr);
+            }
+        }
+        Ok(fn_attrs)
+    }
+}
+
+/// Matches fixtures against the globs of the `ignore` option, `#[fixtures::ignore]`, `#[fixtures::should_panic]` and
+/// `#[fixtures::expect_err]`.
+struct Matchers<'a> {
+    ignore: IgnoreMatcher<'a>,
+    should_panic: IgnoreMatcher<'a>,
+    expect_err: IgnoreMatcher<'a>,
+}
+
+impl<'a> Matchers<'a> {
+    fn new(args: &'a Args, fn_attrs: &'a FnAttrs, root_dir: &std::path::Path) -> syn::Result<Self> {
+        let matcher_error = |(span, err): (proc_macro2::Span, globset::Error)| {
+            syn::Error::new(span, format!("{err}"))
+        };
+        Ok(Matchers {
+            ignore: IgnoreMatcher::new(&args.ignore, &fn_attrs.ignore, root_dir)
+                .map_err(matcher_error)?,
+            should_panic: IgnoreMatcher::for_attributes(&fn_attrs.should_panic, root_dir)
+                .map_err(matcher_error)?,
+            expect_err: IgnoreMatcher::for_attributes(&fn_attrs.expect_err, root_dir)
+                .map_err(matcher_error)?,
+        })
+    }
+}
+
+/// The parameters after those bound to fixtures. Parameters named by the `values` option are bound to each of their
+/// values in turn, and the rest are passed by the caller of the expansions.
+struct ValueBindings<'a> {
+    lists: &'a [ValueList],
+    idents: Vec<&'a Ident>,
+    /// The index of the list of values bound to each parameter, if any.
+    bound_lists: Vec<Option<usize>>,
+    /// The parameters which aren't bound to values, and so are taken by the expansions.
+    unbound_args: Punctuated<&'a FnArg, Token![,]>,
+    /// The name of each value of each list, as used within the names of tests.
+    names: Vec<Vec<String>>,
+    combinations: Vec<Vec<usize>>,
+}
+
+impl<'a> ValueBindings<'a> {
+    fn new(args: &'a Args, fn_args: Vec<&'a FnArg>) -> syn::Result<Self> {
+        let mut idents = Vec::with_capacity(fn_args.len());
+        for arg in &fn_args {
+            let FnArg::Typed(pat_ty) = arg else {
+                return Err(syn::Error::new(arg.span(), "Unexpected receiver argument"));
+            };
+            let Pat::Ident(ident) = pat_ty.pat.as_ref() else {
+                return Err(syn::Error::new(
+                    arg.span(),
+                    "Expected an identifier, but found a pattern",
+                ));
+            };
+            idents.push(&ident.ident);
+        }
+
+        let lists = args
+            .values
+            .as_ref()
+            .map_or(&[][..], |values| values.lists());
+        let mut bound_lists = vec![None; fn_args.len()];
+        let mut names = Vec::with_capacity(lists.len());
+        for (list_index, list) in lists.iter().enumerate() {
+            let Some(position) = idents.iter().position(|ident| **ident == list.param) else {
+                return Err(syn::Error::new(
+                    list.param.span(),
+                    format!(
+                        "Expected a parameter named `{}` after the fixture parameters",
+                        list.param
+                    ),
+                ));
+            };
+            bound_lists[position] = Some(list_index);
+            let mut list_names: Vec<String> = Vec::with_capacity(list.values.len());
+            for value in &list.values {
+                let name = file_name_to_valid_identifier(&format!(
+                    "{}_{}",
+                    list.param,
+                    parse::values::value_name(value)
+                ));
+                if list_names.contains(&name) {
+                    return Err(syn::Error::new(
+                        value.span(),
+                        format!("Multiple values of `{}` are named '{name}'", list.param),
+                    ));
+                }
+                list_names.push(name);
+            }
+            names.push(list_names);
+        }
+
+        let unbound_args = fn_args
+            .iter()
+            .zip(&bound_lists)
+            .filter(|(_, bound_list)| bound_list.is_none())
+            .map(|(fn_arg, _)| *fn_arg)
+            .collect();
+        Ok(ValueBindings {
+            lists,
+            idents,
+            bound_lists,
+            unbound_args,
+            combinations: cartesian_product(names.iter().map(Vec::len)),
+            names,
+        })
+    }
+
+    /// Returns a test for each case and combination of values. `relative_path` is the path of the fixture the cases
+    /// were generated for.
+    fn value_cases(&self, cases: Vec<Case>, relative_path: &str) -> Vec<ValueCase> {
+        let mut value_cases = Vec::with_capacity(cases.len() * self.combinations.len());
+        for case in cases {
+            for combination in &self.combinations {
+                let names = self
+                    .names
+                    .iter()
+                    .zip(combination)
+                    .map(|(names, &index)| names[index].as_str());
+                let name = std::iter::once(case.ident.to_string().as_str())
+                    .chain(names)
+                    .collect::<Vec<_>>()
+                    .join("__");
+                let description = std::iter::once(relative_path.to_string())
+                    .chain(self.lists.iter().zip(combination).map(|(list, &index)| {
+                        let value = &list.values[index];
+                        format!("{} = {}", list.param, quote!(#value))
+                    }))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let remaining_arg_exprs = self
+                    .idents
+                    .iter()
+                    .zip(&self.bound_lists)
+                    .map(|(arg_ident, bound_list)| match bound_list {
+                        Some(list_index) => {
+                            let value = &self.lists[*list_index].values[combination[*list_index]];
+                            quote!(#value)
+                        }
+                        None => quote!(#arg_ident),
+                    })
+                    .collect::<Vec<_>>();
+                value_cases.push(ValueCase {
+                    module_path: case.module_path.clone(),
+                    name,
+                    description,
+                    fixture_arg_expr: case.fixture_arg_expr.clone(),
+                    remaining_arg_exprs,
+                    fixture_attrs: case.fixture_attrs.clone(),
+                });
+            }
+        }
+        value_cases
+    }
+}
+
+/// The options of an invocation which apply to all of its fixtures.
+struct Context<'a> {
+    args: &'a Args,
+    fn_name: &'a Ident,
+    root_dir: std::path::PathBuf,
+    kind: FixtureKind,
+    path_style: PathStyleKind,
+    embed: Option<bool>,
+    matchers: Matchers<'a>,
+}
+
 #[proc_macro_error]
 #[proc_macro_attribute]
 pub fn fixtures(args: TokenStream, input: TokenStream) -> TokenStream {
-    let args = parse_macro_input!(args as parse::args::Args);
+    let args = parse_macro_input!(args as Args);
     let test_fn = parse_macro_input!(input as ItemFn);
-    let fn_name = &test_fn.sig.ident;
+    match expand(&args, &test_fn) {
+        Ok(output) => output.into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+/// Expands `#[fixtures]` on `test_fn` into the function and the module containing its expansions.
+fn expand(args: &Args, test_fn: &ItemFn) -> syn::Result<proc_macro2::TokenStream> {
     let fn_args = &test_fn.sig.inputs;
-    let fn_output = &test_fn.sig.output;
-    let fn_block = &test_fn.block;
 
     if let Some(ignore) = &args.ignore {
         emit_warning!(
@@ -37,181 +312,1119 @@ pub fn fixtures(args: TokenStream, input: TokenStream) -> TokenStream {
         );
     }
 
-    let (fn_attrs, ignore_attrs) = {
-        let mut fn_attrs = Vec::new();
-        let mut ignore_attrs = Vec::new();
-        for attr in &test_fn.attrs {
-            match IgnoreAttribute::try_from_attribute(attr) {
-                Ok(None) => fn_attrs.push(attr),
-                Ok(Some(ignore_config)) => ignore_attrs.push(ignore_config),
-                Err(err) => return err.into_compile_error().into(),
-            }
-        }
-        (fn_attrs, ignore_attrs)
-    };
+    let fn_attrs = FnAttrs::new(&test_fn.attrs)?;
+    let root_dir = root_dir::root_dir(
+        args.root
+            .as_ref()
+            .map_or(RootKind::Manifest, |root| root.kind()),
+    );
+    let kind = args
+        .kind
+        .as_ref()
+        .map_or(FixtureKind::File, |kind| kind.kind());
+    let paths = walk_fixtures(&root_dir, &args.include, kind, args.companions.as_ref());
+    let matchers = Matchers::new(args, &fn_attrs, &root_dir)?;
 
-    let current_dir = std::env::current_dir().expect("failed to get current directory");
-    let mut paths = globwalk::GlobWalkerBuilder::from_patterns(
-        &current_dir,
-        &args
-            .include
-            .paths()
-            .iter()
-            .map(|lit_glob_path| lit_glob_path.value())
-            .collect::<Vec<_>>(),
-    )
-    .build()
-    .expect("failed to build glob walker")
-    .filter_map(Result::ok)
-    .collect::<Vec<_>>();
-    paths.sort_by(|a, b| a.path().cmp(b.path()));
-    let paths_iterator = paths.into_iter();
+    if let Some(format) = &args.format {
+        format.check_feature()?;
+    }
+    let fixture_arg = checked_fixture_arg(args, fn_args.first(), args.format.as_ref(), &paths)?;
+    let case_source = validate_options(args, &fixture_arg, fn_args.first())?;
 
-    let ignore_matcher = match IgnoreMatcher::new(&args.ignore, &ignore_attrs, current_dir) {
-        Ok(matcher) => matcher,
-        Err((span, err)) => {
-            return syn::Error::new(span, format!("{err}"))
-                .to_compile_error()
-                .into();
-        }
+    let ctx = Context {
+        args,
+        fn_name: &test_fn.sig.ident,
+        root_dir,
+        kind,
+        path_style: args
+            .path_style
+            .as_ref()
+            .map_or(PathStyleKind::Absolute, |path_style| path_style.kind()),
+        embed: args.embed.as_ref().map(LitBool::value),
+        matchers,
     };
+    let product_sets = ctx.product_sets(fn_args)?;
+    let bindings = ValueBindings::new(args, fn_args.iter().skip(1 + product_sets.len()).collect())?;
+    let is_test = validate_test_fn(args, &fn_attrs, &test_fn.sig.output)?;
+
+    let (value_cases, case_includes) = ctx.value_cases(
+        paths,
+        &fixture_arg,
+        case_source.as_ref(),
+        &product_sets,
+        &bindings,
+    )?;
+    let value_case_idents = value_case_idents(args, &value_cases);
+    let expansions = value_cases
+        .into_iter()
+        .zip(value_case_idents)
+        .map(|(value_case, ident)| {
+            test_fn_expansion(
+                args,
+                test_fn,
+                &fn_attrs.other,
+                &bindings.unbound_args,
+                value_case,
+                Ident::new(&ident, test_fn.sig.ident.span()),
+            )
+        })
+        .collect::<Vec<_>>();
+    if expansions.is_empty() {
+        return Err(syn::Error::new(
+            args.include.span(),
+            "No valid files found".to_string(),
+        ));
+    }
 
-    let fn_non_path_args = {
-        let mut remaining_args = Punctuated::<&FnArg, Token![,]>::new();
-        for fn_arg in fn_args.iter().skip(1) {
-            remaining_args.push(fn_arg);
+    Ok(expansion_tokens(
+        args,
+        test_fn,
+        is_test,
+        &bindings.unbound_args,
+        &expansions,
+        &case_includes,
+    ))
+}
+
+/// Returns how fixtures are passed to the parameter `fn_arg`, after checking that the fixtures can be passed to it.
+fn checked_fixture_arg(
+    args: &Args,
+    fn_arg: Option<&FnArg>,
+    format: Option<&Format>,
+    entries: &[globwalk::DirEntry],
+) -> syn::Result<FixtureArg> {
+    let fixture_arg = FixtureArg::from_fn_arg(fn_arg, format);
+    if let Some(err) = contents_of_dir_error(&args.kind, &fixture_arg, entries) {
+        return Err(err);
+    }
+    if let Some(err) = not_embeddable_error(args.embed.as_ref(), &fixture_arg, fn_arg) {
+        return Err(err);
+    }
+    Ok(fixture_arg)
+}
+
+/// Checks the combinations of options, returning the option which generates tests for parts of each file, if any.
+fn validate_options<'a>(
+    args: &'a Args,
+    fixture_arg: &FixtureArg,
+    fn_arg: Option<&'a FnArg>,
+) -> syn::Result<Option<CaseSource<'a>>> {
+    if let Some(companions) = &args.companions {
+        if let Some(format) = &args.format {
+            return Err(syn::Error::new(
+                format.span(),
+                "The format option can't be combined with the companions option",
+            ));
         }
-        remaining_args
-    };
-    let fn_non_path_args_idents = {
-        let mut idents = Punctuated::<&Ident, Token![,]>::new();
-        for arg in fn_non_path_args.iter() {
-            if let FnArg::Typed(pat_ty) = arg {
-                if let Pat::Ident(ident) = pat_ty.pat.as_ref() {
-                    idents.push(&ident.ident);
-                    continue;
-                }
-                return syn::Error::new(arg.span(), "Expected an identifier, but found a pattern")
-                    .to_compile_error()
-                    .into();
-            }
-            return syn::Error::new(arg.span(), "Unexpected receiver argument")
-                .to_compile_error()
-                .into();
+        if !fixture_arg.is_fixture() {
+            return Err(syn::Error::new(
+                companions.span(),
+                "The companions option requires the first parameter to be a `fixtures::Fixture`",
+            ));
         }
-        idents
-    };
-
-    let is_test = fn_attrs.iter().any(|attr| {
-        if attr.style != AttrStyle::Outer {
-            return false;
+        if args.embed.as_ref().is_some_and(LitBool::value) {
+            return Err(syn::Error::new(
+                companions.span(),
+                "Companion files can't be embedded. Set `embed = false` to use the companions option.",
+            ));
         }
-        if let Meta::Path(Path {
-            leading_colon: None,
-            segments,
-        }) = &attr.meta
+    }
+
+    let rows = args.rows.as_ref().filter(|rows| rows.value());
+    if let (Some(row_name), None) = (&args.row_name, rows) {
+        return Err(syn::Error::new(
+            row_name.span(),
+            "The row_name option requires `rows = true`",
+        ));
+    }
+    let mut case_sources = Vec::new();
+    if let Some(split) = &args.split {
+        case_sources.push(CaseSource::Split(split));
+    }
+    if let Some(markdown_blocks) = &args.markdown_blocks {
+        if markdown_blocks.value().trim().is_empty()
+            || markdown_blocks.value().contains(char::is_whitespace)
         {
-            if segments.len() != 1 {
-                return false;
-            }
-            let path_segment = segments.first().unwrap();
-            return path_segment.ident == "test";
+            return Err(syn::Error::new(
+                markdown_blocks.span(),
+                "Invalid tag. Expected the first word of the info string of the code blocks, such as 'example'.",
+            ));
         }
-        false
-    });
+        case_sources.push(CaseSource::MarkdownBlocks(markdown_blocks));
+    }
+    if let Some(rows) = rows {
+        case_sources.push(CaseSource::Rows {
+            rows,
+            row_name: args.row_name.as_ref(),
+            ty: match fn_arg {
+                Some(FnArg::Typed(pat_ty)) => Some(&pat_ty.ty),
+                _ => None,
+            },
+        });
+    }
+    if let [first, second, ..] = &case_sources[..] {
+        return Err(syn::Error::new(
+            second.span(),
+            format!(
+                "The {} option can't be combined with the {} option",
+                second.option_name(),
+                first.option_name()
+            ),
+        ));
+    }
+    let case_source = case_sources.into_iter().next();
+    if let Some(case_source) = &case_source {
+        if let Some(format) = &args.format {
+            return Err(syn::Error::new(
+                format.span(),
+                format!(
+                    "The format option can't be combined with the {} option",
+                    case_source.option_name()
+                ),
+            ));
+        }
+        if let Some(companions) = &args.companions {
+            return Err(syn::Error::new(
+                companions.span(),
+                format!(
+                    "The companions option can't be combined with the {} option",
+                    case_source.option_name()
+                ),
+            ));
+        }
+    }
+
+    // The format and companions options only describe the fixtures of the first list of paths
+    if !args.product.is_empty() {
+        let options = [
+            ("format", args.format.as_ref().map(Spanned::span)),
+            ("companions", args.companions.as_ref().map(Spanned::span)),
+        ];
+        if let Some((option, Some(span))) = options.into_iter().find(|(_, span)| span.is_some()) {
+            return Err(syn::Error::new(
+                span,
+                format!("The {option} option can't be combined with multiple lists of paths"),
+            ));
+        }
+    }
+    if let (Some(include), Some(case_source)) = (args.product.first(), &case_source) {
+        return Err(syn::Error::new(
+            include.span(),
+            format!(
+                "Multiple lists of paths can't be combined with the {} option",
+                case_source.option_name()
+            ),
+        ));
+    }
+
+    Ok(case_source)
+}
+
+/// Checks the options and attributes which only apply to test functions, returning whether the function is a test.
+fn validate_test_fn(args: &Args, fn_attrs: &FnAttrs, fn_output: &ReturnType) -> syn::Result<bool> {
+    let custom_test_attrs = args
+        .test_attrs
+        .as_ref()
+        .map_or(&[][..], |test_attrs| test_attrs.paths());
+    let is_test = fn_attrs
+        .other
+        .iter()
+        .any(|attr| test_attribute::is_test_attribute(attr, custom_test_attrs));
 
     if !is_test {
         if let Some(ignore) = &args.ignore {
-            return syn::Error::new(ignore.span(), "The ignore option is only valid for test functions. This function doesn't have a `#[test]` attribute.")
-                .to_compile_error()
-                .into();
+            return Err(not_a_test_error(ignore.span(), "The ignore option"));
+        }
+        if let Some(ignore_attr) = fn_attrs.ignore.first() {
+            return Err(not_a_test_error(ignore_attr.span(), "The ignore option"));
+        }
+        if let Some(should_panic_attr) = fn_attrs.should_panic.first() {
+            return Err(not_a_test_error(
+                should_panic_attr.span(),
+                "The should_panic attribute",
+            ));
+        }
+        if let Some(expect_err_attr) = fn_attrs.expect_err.first() {
+            return Err(not_a_test_error(
+                expect_err_attr.span(),
+                "The expect_err attribute",
+            ));
         }
-        if let Some(ignore_attr) = ignore_attrs.first() {
-            return syn::Error::new(ignore_attr.span(), "The ignore option is only valid for test functions. This function doesn't have a `#[test]` attribute.")
-                .to_compile_error()
-                .into();
+    }
+    if let Some(expect_err_attr) = fn_attrs.expect_err.first() {
+        if !returns_result(fn_output) {
+            let span = match fn_output {
+                ReturnType::Default => expect_err_attr.span(),
+                ReturnType::Type(_, ty) => syn::spanned::Spanned::span(ty),
+            };
+            return Err(syn::Error::new(
+                span,
+                "`#[fixtures::expect_err]` requires the function to return a `Result`",
+            ));
+        }
+    }
+    if let Some(should_panic_attr) = fn_attrs.should_panic.first() {
+        if fn_attrs
+            .other
+            .iter()
+            .any(|attr| attr.path().is_ident("should_panic"))
+        {
+            return Err(syn::Error::new(
+                should_panic_attr.span(),
+                "`#[fixtures::should_panic]` can't be combined with `#[should_panic]`, which applies to all fixtures",
+            ));
         }
     }
 
-    let mut file_names = std::collections::HashMap::new();
+    Ok(is_test)
+}
 
-    let expansions = paths_iterator
-        .filter_map(|path| {
-            let file_name = path.file_name().to_str()?.to_owned();
-            let fn_file_name = file_name_to_valid_identifier(&file_name);
-            let lit_file_path = LitStr::new(
-                path.path()
-                    .to_str()
-                    .expect("file path should be valid UTF-8"),
-                args.include.span(),
-            );
-            let similar_file_names = file_names.entry(file_name.clone()).or_insert(0usize);
-            *similar_file_names += 1;
-            let ident = if *similar_file_names == 1 {
-                Ident::new(&fn_file_name, fn_name.span())
-            } else {
-                Ident::new(
-                    &format!("{fn_file_name}_{similar_file_names}"),
-                    fn_name.span(),
-                )
+impl Context<'_> {
+    /// Walks each further list of paths, which is bound to the next parameter. A test is generated for each combination
+    /// of fixtures.
+    fn product_sets(&self, fn_args: &Punctuated<FnArg, Token![,]>) -> syn::Result<Vec<ProductSet>> {
+        let mut product_sets = Vec::with_capacity(self.args.product.len());
+        for (index, include) in self.args.product.iter().enumerate() {
+            let Some(fn_arg) = fn_args.iter().nth(index + 1) else {
+                return Err(syn::Error::new(
+                    include.span(),
+                    "Expected a parameter for each list of paths",
+                ));
             };
-            let maybe_ignore_attr = match ignore_matcher.matched(path.path()) {
-                MatchResult::Matched {
-                    reason: Some(reason),
-                } => parse_quote!(#[ignore = #reason]),
-                MatchResult::Matched { reason: None } => parse_quote!(#[ignore]),
-                MatchResult::Unmatched => proc_macro2::TokenStream::new(),
+            let entries = walk_fixtures(&self.root_dir, include, self.kind, None);
+            if entries.is_empty() {
+                return Err(syn::Error::new(
+                    include.span(),
+                    "No valid files found".to_string(),
+                ));
+            }
+            let fixture_arg = checked_fixture_arg(self.args, Some(fn_arg), None, &entries)?;
+            let candidates = entries
+                .iter()
+                .map(|entry| identifiers::Candidate {
+                    module: String::new(),
+                    ident: file_name_to_valid_identifier(&entry.file_name().to_string_lossy()),
+                    relative_path: relative_path(entry.path(), &self.root_dir),
+                })
+                .collect::<Vec<_>>();
+            let fixture_arg_exprs = entries
+                .iter()
+                .map(|entry| {
+                    let fixture_path =
+                        FixturePath::new(entry.path(), self.path_style, include.span())?;
+                    fixture_arg_tokens(
+                        &fixture_arg,
+                        entry,
+                        &fixture_path,
+                        &self.root_dir,
+                        self.embed,
+                        include.span(),
+                    )
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+            product_sets.push(ProductSet {
+                names: identifiers::disambiguate(&candidates),
+                relative_paths: candidates
+                    .into_iter()
+                    .map(|candidate| candidate.relative_path)
+                    .collect(),
+                fixture_arg_exprs,
+                fixture_attrs: entries
+                    .iter()
+                    .map(|entry| FixtureAttrs::new(&self.matchers, entry.path()))
+                    .collect(),
+            });
+        }
+        Ok(product_sets)
+    }
+
+    /// Returns the tests for every fixture, case, combination of fixtures and combination of values, along with the
+    /// tokens which include the files the cases were read from.
+    fn value_cases(
+        &self,
+        paths: Vec<globwalk::DirEntry>,
+        fixture_arg: &FixtureArg,
+        case_source: Option<&CaseSource>,
+        product_sets: &[ProductSet],
+        bindings: &ValueBindings,
+    ) -> syn::Result<(Vec<ValueCase>, Vec<proc_macro2::TokenStream>)> {
+        let (module_paths, idents) = self.fixture_idents(&paths)?;
+        let combination_idents =
+            self.combination_idents(&paths, &module_paths, &idents, product_sets);
+
+        let mut value_cases = Vec::new();
+        let mut case_includes = Vec::new();
+        for (((path, module_path), ident), combination_idents) in paths
+            .into_iter()
+            .zip(module_paths)
+            .zip(idents)
+            .zip(combination_idents)
+        {
+            let ident = Ident::new(&ident, self.fn_name.span());
+            let fixture_path =
+                FixturePath::new(path.path(), self.path_style, self.args.include.span())?;
+            let fixture_attrs = FixtureAttrs::new(&self.matchers, path.path());
+            let cases = match case_source {
+                Some(case_source) => {
+                    // Each file gets its own module, containing a test for each case
+                    let module_path = [module_path, vec![ident]].concat();
+                    let (cases, include_tokens) = self.file_cases(
+                        &path,
+                        &fixture_path,
+                        case_source,
+                        module_path,
+                        &fixture_attrs,
+                    )?;
+                    case_includes.push(include_tokens);
+                    cases
+                }
+                None => {
+                    let case = Case {
+                        module_path,
+                        ident,
+                        fixture_arg_expr: self.fixture_arg_expr(
+                            fixture_arg,
+                            &path,
+                            &fixture_path,
+                        )?,
+                        fixture_attrs,
+                    };
+                    if product_sets.is_empty() {
+                        vec![case]
+                    } else {
+                        self.product_cases(case, product_sets, combination_idents)
+                    }
+                }
             };
-            let tokens = quote! {
-                #(#fn_attrs)*
-                #maybe_ignore_attr
-                pub fn #ident(#fn_non_path_args) #fn_output {
-                    #fn_name(::std::path::Path::new(#lit_file_path), #fn_non_path_args_idents)
+            value_cases
+                .extend(bindings.value_cases(cases, &relative_path(path.path(), &self.root_dir)));
+        }
+        Ok((value_cases, case_includes))
+    }
+
+    /// Returns the module path and identifier of the test for each fixture. Identifiers are chosen for all fixtures up
+    /// front, so collisions can be resolved independently of walk order.
+    fn fixture_idents(
+        &self,
+        paths: &[globwalk::DirEntry],
+    ) -> syn::Result<(Vec<Vec<Ident>>, Vec<String>)> {
+        let args = self.args;
+        let nested_base_dir = args
+            .nested
+            .as_ref()
+            .is_some_and(LitBool::value)
+            .then(|| nested::base_dir(&self.root_dir, args.include.paths()));
+        let glob_captures = match args.name.as_ref().filter(|name| name.has_captures()) {
+            Some(_) => Some(GlobCaptures::new(args.include.paths())?),
+            None => None,
+        };
+        let names_base_dir = nested_base_dir
+            .clone()
+            .unwrap_or_else(|| nested::base_dir(&self.root_dir, args.include.paths()));
+
+        let mut module_paths = Vec::with_capacity(paths.len());
+        let mut sanitized_names = Vec::with_capacity(paths.len());
+        let mut candidates = Vec::with_capacity(paths.len());
+        for entry in paths {
+            let path = entry.path();
+            let module_path = nested_base_dir.as_ref().map_or_else(Vec::new, |base_dir| {
+                nested::module_path(path, base_dir, self.fn_name.span())
+            });
+            let name = match &args.name {
+                Some(name_template) => {
+                    let captures = glob_captures.as_ref().and_then(|glob_captures| {
+                        glob_captures.captures(path.strip_prefix(&self.root_dir).ok()?)
+                    });
+                    templated_name(name_template, path, &names_base_dir, captures.as_deref())?
                 }
+                None => entry.file_name().to_string_lossy().into_owned(),
             };
-            Some(TestFnExpansion { ident, tokens })
+            let sanitized_name = file_name_to_valid_identifier(&name);
+            candidates.push(identifiers::Candidate {
+                module: module_name(&module_path),
+                ident: identifiers::shorten(sanitized_name.clone()),
+                relative_path: relative_path(path, &self.root_dir),
+            });
+            module_paths.push(module_path);
+            sanitized_names.push(sanitized_name);
+        }
+        let idents = match &args.name {
+            // Unlike file names, templated names aren't disambiguated, since the user chose them
+            Some(name_template) => {
+                if let Some((first, second)) = identifiers::find_collision(&candidates) {
+                    return Err(syn::Error::new(
+                        name_template.span(),
+                        format!(
+                            "The name template produces the name '{}' for both '{}' and '{}'",
+                            first.ident, first.relative_path, second.relative_path,
+                        ),
+                    ));
+                }
+                candidates
+                    .into_iter()
+                    .map(|candidate| candidate.ident)
+                    .collect::<Vec<_>>()
+            }
+            None => unique_idents(
+                &candidates,
+                &sanitized_names,
+                args.include.span(),
+                |index| candidates[index].relative_path.clone(),
+            ),
+        };
+        Ok((module_paths, idents))
+    }
+
+    /// Returns each combination of fixtures of the further lists of paths, and the name of its test, for each fixture.
+    /// Combinations are named by joining the names of their fixtures, e.g. `cfg_a_dot_toml__input_b_dot_txt`.
+    fn combination_idents(
+        &self,
+        paths: &[globwalk::DirEntry],
+        module_paths: &[Vec<Ident>],
+        idents: &[String],
+        product_sets: &[ProductSet],
+    ) -> Vec<Vec<(Vec<usize>, String)>> {
+        if product_sets.is_empty() {
+            return vec![Vec::new(); idents.len()];
+        }
+        let combinations = cartesian_product(product_sets.iter().map(|set| set.names.len()));
+        let mut names = Vec::new();
+        let mut candidates = Vec::new();
+        for (index, ident) in idents.iter().enumerate() {
+            for combination in &combinations {
+                let (set_names, set_relative_paths): (Vec<_>, Vec<_>) = product_sets
+                    .iter()
+                    .zip(combination)
+                    .map(|(set, &index)| {
+                        (
+                            set.names[index].as_str(),
+                            set.relative_paths[index].as_str(),
+                        )
+                    })
+                    .unzip();
+                let name = [&[ident.as_str()], &set_names[..]].concat().join("__");
+                candidates.push(identifiers::Candidate {
+                    module: module_name(&module_paths[index]),
+                    ident: identifiers::shorten(name.clone()),
+                    relative_path: [
+                        &[relative_path(paths[index].path(), &self.root_dir).as_str()],
+                        &set_relative_paths[..],
+                    ]
+                    .concat()
+                    .join(" + "),
+                });
+                names.push(name);
+            }
+        }
+        let mut combined_idents =
+            unique_idents(&candidates, &names, self.args.include.span(), |index| {
+                candidates[index].relative_path.clone()
+            })
+            .into_iter();
+        idents
+            .iter()
+            .map(|_| {
+                combinations
+                    .iter()
+                    .map(|combination| {
+                        (
+                            combination.clone(),
+                            combined_idents
+                                .next()
+                                .expect("there should be an identifier for each combination"),
+                        )
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns the expression passed to the first parameter for the fixture at `path`.
+    fn fixture_arg_expr(
+        &self,
+        fixture_arg: &FixtureArg,
+        path: &globwalk::DirEntry,
+        fixture_path: &FixturePath,
+    ) -> syn::Result<proc_macro2::TokenStream> {
+        match &self.args.companions {
+            Some(companions) => {
+                companions::fixture_tokens(path.path(), fixture_path, companions, self.path_style)
+            }
+            None => fixture_arg_tokens(
+                fixture_arg,
+                path,
+                fixture_path,
+                &self.root_dir,
+                self.embed,
+                self.args.include.span(),
+            ),
+        }
+    }
+
+    /// Returns a test for each case of the file at `path`, within `module_path`, along with the tokens which include
+    /// the file.
+    fn file_cases(
+        &self,
+        path: &globwalk::DirEntry,
+        fixture_path: &FixturePath,
+        case_source: &CaseSource,
+        module_path: Vec<Ident>,
+        fixture_attrs: &FixtureAttrs,
+    ) -> syn::Result<(Vec<Case>, proc_macro2::TokenStream)> {
+        let (cases, include_tokens) = file_cases::file_cases(
+            path.path(),
+            fixture_path,
+            case_source,
+            relative_path(path.path(), &self.root_dir),
+        )?;
+        let cases = cases
+            .into_iter()
+            .map(|(ident, fixture_arg_expr)| Case {
+                module_path: module_path.clone(),
+                ident: Ident::new(&ident, self.fn_name.span()),
+                fixture_arg_expr,
+                fixture_attrs: fixture_attrs.clone(),
+            })
+            .collect();
+        Ok((cases, include_tokens))
+    }
+
+    /// Returns a test for each combination of `case` with the fixtures of the further lists of paths. A combination is
+    /// ignored (or should panic, or return an error) if any of its fixtures are.
+    fn product_cases(
+        &self,
+        case: Case,
+        product_sets: &[ProductSet],
+        combination_idents: Vec<(Vec<usize>, String)>,
+    ) -> Vec<Case> {
+        let fixture_arg_expr = &case.fixture_arg_expr;
+        combination_idents
+            .into_iter()
+            .map(|(combination, combined_ident)| {
+                let set_fixture_arg_exprs = product_sets
+                    .iter()
+                    .zip(&combination)
+                    .map(|(set, &index)| &set.fixture_arg_exprs[index]);
+                let fixture_attrs = product_sets.iter().zip(&combination).fold(
+                    case.fixture_attrs.clone(),
+                    |fixture_attrs, (set, &index)| fixture_attrs.or(&set.fixture_attrs[index]),
+                );
+                Case {
+                    module_path: case.module_path.clone(),
+                    ident: Ident::new(&combined_ident, self.fn_name.span()),
+                    fixture_arg_expr: quote!(#fixture_arg_expr, #(#set_fixture_arg_exprs),*),
+                    fixture_attrs,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Returns the path of the module of a test, relative to the module named after the function, as used to find
+/// colliding identifiers.
+fn module_name(module_path: &[Ident]) -> String {
+    module_path
+        .iter()
+        .map(Ident::to_string)
+        .collect::<Vec<_>>()
+        .join("::")
+}
+
+/// Returns the identifier of each test. Tests named after their values may collide with each other, or with tests for
+/// other fixtures.
+fn value_case_idents(args: &Args, value_cases: &[ValueCase]) -> Vec<String> {
+    let Some(values) = &args.values else {
+        return value_cases
+            .iter()
+            .map(|value_case| value_case.name.clone())
+            .collect();
+    };
+    let candidates = value_cases
+        .iter()
+        .map(|value_case| identifiers::Candidate {
+            module: module_name(&value_case.module_path),
+            ident: identifiers::shorten(value_case.name.clone()),
+            relative_path: value_case.description.clone(),
         })
         .collect::<Vec<_>>();
+    let names = value_cases
+        .iter()
+        .map(|value_case| value_case.name.clone())
+        .collect::<Vec<_>>();
+    unique_idents(&candidates, &names, values.span(), |index| {
+        candidates[index].relative_path.clone()
+    })
+}
 
-    if expansions.is_empty() {
-        return syn::Error::new(args.include.span(), "No valid files found".to_string())
-            .into_compile_error()
-            .into();
-    }
+/// Returns the function generated for a test, which calls the decorated function with its fixtures and values.
+fn test_fn_expansion(
+    args: &Args,
+    test_fn: &ItemFn,
+    fn_attrs: &[&Attribute],
+    fn_non_path_args: &Punctuated<&FnArg, Token![,]>,
+    value_case: ValueCase,
+    ident: Ident,
+) -> TestFnExpansion {
+    let Signature {
+        ident: fn_name,
+        asyncness: fn_asyncness,
+        unsafety: fn_unsafety,
+        abi: fn_abi,
+        generics: fn_generics,
+        output: fn_output,
+        ..
+    } = &test_fn.sig;
+    let fn_where_clause = &fn_generics.where_clause;
+    let ValueCase {
+        module_path,
+        fixture_arg_expr,
+        remaining_arg_exprs,
+        fixture_attrs,
+        ..
+    } = value_case;
 
-    let fn_expansions = expansions.iter().map(|expansion| &expansion.tokens);
-    let expansion_idents = {
-        let mut impl_idents = Punctuated::<&Ident, Token![,]>::new();
-        for expansion in expansions.iter() {
-            impl_idents.push(&expansion.ident);
+    // The normalizer of async functions is set while their future is polled instead, since it may be polled on another
+    // thread after each `.await`
+    let maybe_normalize_stmt = match (&args.normalize, fn_asyncness) {
+        (Some(normalize), None) => quote! {
+            let _normalizer = ::fixtures::__private::set_normalizer(#normalize);
+        },
+        _ => proc_macro2::TokenStream::new(),
+    };
+    // Each implementation is called with its own copy of the fixtures, before the function itself
+    let maybe_compare_stmt = match &args.compare {
+        Some(compare) => {
+            let implementations = compare.paths().iter().map(|path| {
+                let name = quote!(#path).to_string().replace(' ', "");
+                quote!((#name, #path(#fixture_arg_expr)))
+            });
+            quote! {
+                ::fixtures::compare::assert_agree([#(#implementations),*]);
+            }
+        }
+        None => proc_macro2::TokenStream::new(),
+    };
+    let call = quote!(#fn_name(#fixture_arg_expr, #(#remaining_arg_exprs),*));
+    let call = match (fn_asyncness, &args.normalize) {
+        (Some(_), Some(normalize)) => {
+            quote!(::fixtures::__private::with_normalizer(#normalize, #call).await)
+        }
+        (Some(_), None) => quote!(#call.await),
+        (None, _) => call,
+    };
+    let call = match fn_unsafety {
+        Some(_) => quote!(unsafe { #call }),
+        None => call,
+    };
+    let call = match &fixture_attrs.expect_err {
+        Some(matches) => quote!(::fixtures::__private::expect_err(#call, #matches)),
+        None => call,
+    };
+    let body = quote! {
+        {
+            #maybe_normalize_stmt
+            #maybe_compare_stmt
+            #call
         }
-        impl_idents
     };
 
+    // Names of combinations and values are joined using `__`, which isn't snake case
+    let maybe_allow_attr = if ident.to_string().contains("__") {
+        quote!(#[allow(non_snake_case)])
+    } else {
+        proc_macro2::TokenStream::new()
+    };
+    let tokens = quote! {
+        #(#fn_attrs)*
+        #fixture_attrs
+        #maybe_allow_attr
+        pub #fn_asyncness #fn_unsafety #fn_abi fn #ident #fn_generics(#fn_non_path_args)
+        #fn_output
+        #fn_where_clause
+        #body
+    };
+    TestFnExpansion {
+        module_path,
+        ident,
+        body,
+        tokens,
+    }
+}
+
+/// Returns the decorated function, followed by the module containing its expansions and their `EXPANSIONS` constant.
+fn expansion_tokens(
+    args: &Args,
+    test_fn: &ItemFn,
+    is_test: bool,
+    fn_non_path_args: &Punctuated<&FnArg, Token![,]>,
+    expansions: &[TestFnExpansion],
+    case_includes: &[proc_macro2::TokenStream],
+) -> proc_macro2::TokenStream {
+    let ItemFn {
+        vis: fn_vis,
+        sig: fn_sig,
+        block: fn_block,
+        ..
+    } = test_fn;
+    let Signature {
+        ident: fn_name,
+        constness: fn_constness,
+        asyncness: fn_asyncness,
+        unsafety: fn_unsafety,
+        abi: fn_abi,
+        generics: fn_generics,
+        inputs: fn_args,
+        output: fn_output,
+        ..
+    } = fn_sig;
+    let fn_where_clause = &fn_generics.where_clause;
+
+    let fn_expansions = nested::nested_tokens(
+        expansions
+            .iter()
+            .map(|expansion| (&expansion.module_path[..], &expansion.tokens)),
+    );
+    let expansion_paths = expansions
+        .iter()
+        .map(|expansion| {
+            let TestFnExpansion {
+                module_path, ident, ..
+            } = expansion;
+            quote!(#(#module_path::)* #ident)
+        })
+        .collect::<Vec<_>>();
+    let expansion_bodies = expansions
+        .iter()
+        .map(|expansion| expansion.body.clone())
+        .collect::<Vec<_>>();
+
     let maybe_cfg_test_attr = if is_test {
         parse_quote!(#[cfg(test)])
     } else {
         proc_macro2::TokenStream::new()
     };
 
-    let output = quote! {
+    // Async test functions are rewritten by the test attribute of their runtime, so the type of their expansions isn't
+    // known, and they can't be referred to using function pointers
+    let expansions_const = signature::expansions_const(
+        fn_sig,
+        &fn_non_path_args.iter().copied().collect::<Vec<_>>(),
+        &expansion_paths,
+        &expansion_bodies,
+        !(is_test && fn_asyncness.is_some()),
+    );
+
+    let module_name = args.module.as_ref().map_or_else(
+        || fn_name.clone(),
+        |module| {
+            Ident::new(
+                &file_name_to_valid_identifier(&module.value()),
+                module.span(),
+            )
+        },
+    );
+
+    quote! {
         #maybe_cfg_test_attr
-        fn #fn_name(#fn_args) #fn_output #fn_block
+        #fn_vis #fn_constness #fn_asyncness #fn_unsafety #fn_abi fn #fn_name #fn_generics(#fn_args)
+        #fn_output
+        #fn_where_clause
+        #fn_block
         #maybe_cfg_test_attr
-        mod #fn_name {
+        #fn_vis mod #module_name {
             use super::*;
 
-            #(#fn_expansions)*
+            #fn_expansions
+
+            #(#case_includes)*
 
-            pub const EXPANSIONS: &[fn(#fn_non_path_args) #fn_output] = &[#expansion_idents];
+            #expansions_const
         }
+    }
+}
+
+/// Returns an error for an option or attribute which is only valid for test functions, such as "The ignore option".
+fn not_a_test_error(span: proc_macro2::Span, what: &str) -> syn::Error {
+    syn::Error::new(
+        span,
+        format!(
+            "{what} is only valid for test functions. This function doesn't have a recognized test attribute, such as \
+             `#[test]`. Custom test attributes can be registered using the `test_attrs` option."
+        ),
+    )
+}
+
+/// Returns every combination of indices into lists of the given lengths, in lexicographic order.
+fn cartesian_product(lens: impl Iterator<Item = usize>) -> Vec<Vec<usize>> {
+    lens.fold(vec![Vec::new()], |combinations, len| {
+        combinations
+            .into_iter()
+            .flat_map(|combination| {
+                (0..len).map(move |index| [&combination[..], &[index]].concat())
+            })
+            .collect()
+    })
+}
+
+/// Returns an error at the `kind` option if a directory was matched, but the parameter expects the contents of a file.
+fn contents_of_dir_error(
+    kind: &Option<parse::kind::Kind>,
+    fixture_arg: &FixtureArg,
+    entries: &[globwalk::DirEntry],
+) -> Option<syn::Error> {
+    let kind = kind.as_ref()?;
+    let is_contents = matches!(
+        fixture_arg,
+        FixtureArg::Str | FixtureArg::Bytes | FixtureArg::String | FixtureArg::VecU8
+    );
+    if !is_contents || !entries.iter().any(|entry| entry.path().is_dir()) {
+        return None;
+    }
+    Some(syn::Error::new(
+        kind.span(),
+        "Directory fixtures need a path or `FromFixture` parameter, rather than the contents of a file",
+    ))
+}
+
+/// Whether the return type is syntactically a `Result`, such as `Result<(), E>` or `std::io::Result<()>`.
+fn returns_result(output: &ReturnType) -> bool {
+    let ReturnType::Type(_, ty) = output else {
+        return false;
     };
+    let syn::Type::Path(type_path) = &**ty else {
+        return false;
+    };
+    type_path.qself.is_none()
+        && type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Result")
+}
+
+/// Returns the expression passed to the parameter bound to the fixture at `path`.
+fn fixture_arg_tokens(
+    fixture_arg: &FixtureArg,
+    path: &globwalk::DirEntry,
+    fixture_path: &FixturePath,
+    root_dir: &std::path::Path,
+    embed: Option<bool>,
+    span: proc_macro2::Span,
+) -> syn::Result<proc_macro2::TokenStream> {
+    // Without the `embed` option, the `embed` feature determines whether files are embedded
+    let is_dir = path.file_type().is_dir();
+    let explicit = embed.is_some();
+    let embed = embed.unwrap_or(cfg!(feature = "embed") && !is_dir);
+    if embed && is_dir {
+        return Err(syn::Error::new(
+            span,
+            format!(
+                "The directory '{}' can't be embedded. Only files can be embedded.",
+                path.path().display()
+            ),
+        ));
+    }
+    let embedded_path = embed.then(|| {
+        let relative_path = path.path().strip_prefix(root_dir).unwrap_or(path.path());
+        LitStr::new(
+            relative_path
+                .to_str()
+                .expect("file path should be valid UTF-8"),
+            span,
+        )
+    });
+    let embed = match (&embedded_path, explicit) {
+        (None, _) => Embed::No,
+        (Some(embedded_path), true) => Embed::Required(embedded_path),
+        (Some(embedded_path), false) => Embed::Preferred(embedded_path),
+    };
+    Ok(fixture_arg.to_tokens(fixture_path, embed))
+}
 
-    output.into()
+/// Returns an error if `embed = true` is set, but the parameter is an `impl Trait`, which is only ever passed the path
+/// of the fixture. Other types which can't be embedded are reported by the `FromEmbedded` bound of the expansion.
+fn not_embeddable_error(
+    embed: Option<&LitBool>,
+    fixture_arg: &FixtureArg,
+    fn_arg: Option<&FnArg>,
+) -> Option<syn::Error> {
+    let embed = embed.filter(|embed| embed.value())?;
+    match (fixture_arg, fn_arg) {
+        (FixtureArg::Path, Some(FnArg::Typed(_))) => Some(syn::Error::new(
+            embed.span(),
+            "An `impl Trait` parameter can't be embedded. Use a type which implements `fixtures::FromEmbedded`, \
+             or set `embed = false`.",
+        )),
+        _ => None,
+    }
+}
+
+impl FixtureAttrs {
+    fn new(matchers: &Matchers, path: &std::path::Path) -> Self {
+        let ignore = match matchers.ignore.matched(path) {
+            MatchResult::Matched {
+                value: Some(reason),
+            } => parse_quote!(#[ignore = #reason]),
+            MatchResult::Matched { value: None } => parse_quote!(#[ignore]),
+            MatchResult::Unmatched => proc_macro2::TokenStream::new(),
+        };
+        let should_panic = match matchers.should_panic.matched(path) {
+            MatchResult::Matched {
+                value: Some(expected),
+            } => parse_quote!(#[should_panic(expected = #expected)]),
+            MatchResult::Matched { value: None } => parse_quote!(#[should_panic]),
+            MatchResult::Unmatched => proc_macro2::TokenStream::new(),
+        };
+        let expect_err = match matchers.expect_err.matched(path) {
+            MatchResult::Matched {
+                value: Some(matches),
+            } => Some(quote!(::core::option::Option::Some(#matches))),
+            MatchResult::Matched { value: None } => Some(quote!(::core::option::Option::None)),
+            MatchResult::Unmatched => None,
+        };
+        FixtureAttrs {
+            ignore,
+            should_panic,
+            expect_err,
+        }
+    }
+
+    /// Combines the attributes of the fixtures in a combination, which is ignored (or should panic, or return an error)
+    /// if any of its fixtures are.
+    fn or(self, other: &FixtureAttrs) -> Self {
+        let or = |tokens: proc_macro2::TokenStream, other: &proc_macro2::TokenStream| {
+            if tokens.is_empty() {
+                other.clone()
+            } else {
+                tokens
+            }
+        };
+        FixtureAttrs {
+            ignore: or(self.ignore, &other.ignore),
+            should_panic: or(self.should_panic, &other.should_panic),
+            expect_err: self.expect_err.or_else(|| other.expect_err.clone()),
+        }
+    }
+}
+
+impl quote::ToTokens for FixtureAttrs {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        tokens.extend(self.ignore.clone());
+        tokens.extend(self.should_panic.clone());
+    }
+}
+
+/// Returns the fixtures matched by the glob patterns, sorted by path.
+fn walk_fixtures(
+    root_dir: &std::path::Path,
+    include: &Paths,
+    kind: FixtureKind,
+    companions: Option<&Companions>,
+) -> Vec<globwalk::DirEntry> {
+    let mut paths = globwalk::GlobWalkerBuilder::from_patterns(
+        root_dir,
+        &include
+            .paths()
+            .iter()
+            .map(|lit_glob_path| lit_glob_path.value())
+            .collect::<Vec<_>>(),
+    )
+    .build()
+    .expect("failed to build glob walker")
+    .filter_map(Result::ok)
+    .collect::<Vec<_>>();
+    paths.sort_by(|a, b| a.path().cmp(b.path()));
+
+    let mut matched_dirs = Vec::new();
+    paths.retain(|entry| {
+        let path = entry.path();
+        // Paths are sorted, so directories are always visited before their contents
+        if matched_dirs.iter().any(|dir| path.starts_with(dir)) {
+            return false;
+        }
+        let is_dir = path.is_dir();
+        if is_dir && kind != FixtureKind::File {
+            matched_dirs.push(path.to_path_buf());
+        }
+        match kind {
+            FixtureKind::File => path.is_file(),
+            FixtureKind::Dir => is_dir,
+            FixtureKind::Any => true,
+        }
+    });
+    if let Some(companions) = companions {
+        paths.retain(|entry| !companions::is_companion(entry.path(), companions));
+    }
+    // Fixtures can only be named if their file name is valid UTF-8
+    paths.retain(|entry| entry.file_name().to_str().is_some());
+    paths
+}
+
+/// Returns a unique identifier for each candidate, and emits a warning listing the candidates which were renamed.
+/// `describe` returns how the candidate at the given index is referred to in the warning.
+fn unique_idents(
+    candidates: &[identifiers::Candidate],
+    sanitized_names: &[String],
+    span: proc_macro2::Span,
+    describe: impl Fn(usize) -> String,
+) -> Vec<String> {
+    let idents = identifiers::disambiguate(candidates);
+    let renamed = sanitized_names
+        .iter()
+        .zip(&idents)
+        .enumerate()
+        .filter(|(_, (sanitized_name, ident))| sanitized_name != ident)
+        .map(|(index, (_, ident))| format!("'{}' as '{ident}'", describe(index)))
+        .collect::<Vec<_>>();
+    if !renamed.is_empty() {
+        emit_warning!(
+            span,
+            "The names of some fixtures are too long, or collide with other fixtures once converted to identifiers. The following fixtures were renamed: {}",
+            renamed.join(", ")
+        );
+    }
+    idents
+}
+
+/// Renders the name of the test for the fixture at `path`. The name isn't necessarily a valid identifier.
+fn templated_name(
+    name_template: &NameTemplate,
+    path: &std::path::Path,
+    base_dir: &std::path::Path,
+    captures: Option<&[String]>,
+) -> syn::Result<String> {
+    let to_str = |os_str: Option<&std::ffi::OsStr>| {
+        os_str
+            .and_then(std::ffi::OsStr::to_str)
+            .unwrap_or_default()
+            .to_owned()
+    };
+    let rel_path = relative_path(path, base_dir);
+    name_template.render(&NameContext {
+        file: &to_str(path.file_name()),
+        stem: &to_str(path.file_stem()),
+        ext: &to_str(path.extension()),
+        dir: &to_str(path.parent().and_then(std::path::Path::file_name)),
+        rel_path: &rel_path,
+        captures,
+    })
+}
+
+/// Returns `path` relative to `base_dir`, using `/` as the separator on all platforms.
+fn relative_path(path: &std::path::Path, base_dir: &std::path::Path) -> String {
+    path.strip_prefix(base_dir)
+        .unwrap_or(path)
+        .iter()
+        .filter_map(std::ffi::OsStr::to_str)
+        .collect::<Vec<_>>()
+        .join("/")
 }
 
 /// See https://doc.rust-lang.org/reference/identifiers.html
 fn file_name_to_valid_identifier(file_name: &str) -> String {
+    // Identifiers are normalized to NFC by rustc, so file names which only differ in their normalization would collide
+    let file_name = &file_name.nfc().collect::<String>();
+
     if file_name.is_empty() {
         return "test".to_string();
     }
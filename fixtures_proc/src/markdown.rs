@@ -0,0 +1,238 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// A fenced code block extracted from a Markdown file using the `markdown_blocks` option.
+#[derive(Debug, PartialEq, Eq)]
+pub struct MarkdownBlock {
+    /// The text of the nearest preceding ATX heading, if any.
+    pub heading: Option<String>,
+    pub info: String,
+    /// The 1-based line number of the first line of the block's contents.
+    pub line: usize,
+    pub contents: String,
+    /// The contents of the `output` block which immediately follows the block, if any.
+    pub output: Option<String>,
+}
+
+impl MarkdownBlock {
+    /// Returns an expression of type `::fixtures::MarkdownBlock`.
+    pub fn to_tokens(&self, path_tokens: &TokenStream) -> TokenStream {
+        let MarkdownBlock {
+            heading,
+            info,
+            line,
+            contents,
+            output,
+        } = self;
+        let heading = option_tokens(heading.as_deref());
+        let output = option_tokens(output.as_deref());
+        quote! {
+            ::fixtures::MarkdownBlock::new(#path_tokens, #heading, #info, #line, #contents, #output)
+        }
+    }
+}
+
+fn option_tokens(value: Option<&str>) -> TokenStream {
+    match value {
+        Some(value) => quote!(::std::option::Option::Some(#value)),
+        None => quote!(::std::option::Option::None),
+    }
+}
+
+/// A fenced code block, before blocks are matched against the tag and paired with their output.
+struct Fence {
+    heading: Option<String>,
+    info: String,
+    line: usize,
+    contents: String,
+}
+
+/// Returns the fenced code blocks whose info string starts with `tag`. Each block is paired with the next fenced block
+/// if its info string starts with `output`, and no heading separates them.
+pub fn blocks(contents: &str, tag: &str) -> Vec<MarkdownBlock> {
+    let fences = fences(contents);
+    let mut blocks = Vec::new();
+    let mut fences = fences.into_iter().peekable();
+    while let Some(fence) = fences.next() {
+        if first_word(&fence.info) != tag {
+            continue;
+        }
+        let output = fences
+            .next_if(|next| first_word(&next.info) == "output" && next.heading == fence.heading)
+            .map(|output| output.contents);
+        blocks.push(MarkdownBlock {
+            heading: fence.heading,
+            info: fence.info,
+            line: fence.line,
+            contents: fence.contents,
+            output,
+        });
+    }
+    blocks
+}
+
+fn first_word(info: &str) -> &str {
+    info.split_whitespace().next().unwrap_or_default()
+}
+
+fn fences(contents: &str) -> Vec<Fence> {
+    let mut fences = Vec::new();
+    let mut heading = None;
+    // The fence character and length of the open fence, along with the fence being collected
+    let mut open: Option<(char, usize, Fence)> = None;
+    for (index, line) in contents.split_inclusive('\n').enumerate() {
+        let trimmed = line.trim_end_matches(['\n', '\r']);
+        match &mut open {
+            Some((fence_char, fence_len, fence)) => {
+                if let Some((close_char, close_len, rest)) = fence_marker(trimmed) {
+                    if close_char == *fence_char && close_len >= *fence_len && rest.is_empty() {
+                        let (_, _, fence) = open.take().expect("fence should be open");
+                        fences.push(fence);
+                        continue;
+                    }
+                }
+                fence.contents.push_str(line);
+            }
+            None => {
+                if let Some((fence_char, fence_len, info)) = fence_marker(trimmed) {
+                    // Backtick fences can't contain backticks in their info string
+                    if fence_char == '~' || !info.contains('`') {
+                        let fence = Fence {
+                            heading: heading.clone(),
+                            info: info.to_string(),
+                            line: index + 2,
+                            contents: String::new(),
+                        };
+                        open = Some((fence_char, fence_len, fence));
+                        continue;
+                    }
+                }
+                if let Some(text) = atx_heading(trimmed) {
+                    heading = Some(text.to_string());
+                }
+            }
+        }
+    }
+    // An unclosed fence extends to the end of the document
+    if let Some((_, _, fence)) = open {
+        fences.push(fence);
+    }
+    fences
+}
+
+/// Returns the fence character, the length of the fence, and the trimmed text after it.
+fn fence_marker(line: &str) -> Option<(char, usize, &str)> {
+    let line = strip_indent(line)?;
+    let fence_char = line.chars().next().filter(|c| *c == '`' || *c == '~')?;
+    let fence_len = line.chars().take_while(|c| *c == fence_char).count();
+    if fence_len < 3 {
+        return None;
+    }
+    Some((fence_char, fence_len, line[fence_len..].trim()))
+}
+
+/// Returns the text of an ATX heading, such as `## Heading ##`.
+fn atx_heading(line: &str) -> Option<&str> {
+    let line = strip_indent(line)?;
+    let level = line.chars().take_while(|c| *c == '#').count();
+    if !(1..=6).contains(&level) {
+        return None;
+    }
+    let rest = &line[level..];
+    if !rest.is_empty() && !rest.starts_with([' ', '\t']) {
+        return None;
+    }
+    let text = rest.trim();
+    // The optional closing sequence must be preceded by a space
+    let without_closing = text.trim_end_matches('#');
+    let text = if without_closing.is_empty() || without_closing.ends_with([' ', '\t']) {
+        without_closing.trim_end()
+    } else {
+        text
+    };
+    Some(text)
+}
+
+/// Strips up to 3 spaces of indentation. Lines indented by more are indented code, rather than a fence or heading.
+fn strip_indent(line: &str) -> Option<&str> {
+    let indent = line.len() - line.trim_start_matches(' ').len();
+    (indent <= 3).then(|| &line[indent..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn correctly_extracts_tagged_blocks() {
+        let contents = "\
+# Spec
+
+## Tabs ##
+
+```example
+\tfoo
+```
+
+```output
+<pre>foo</pre>
+```
+
+Some prose.
+
+~~~~ example rust
+```
+nested
+```
+~~~~
+
+```other
+ignored
+```
+";
+
+        let blocks = blocks(contents, "example");
+
+        assert_eq!(
+            blocks,
+            [
+                MarkdownBlock {
+                    heading: Some("Tabs".to_string()),
+                    info: "example".to_string(),
+                    line: 6,
+                    contents: "\tfoo\n".to_string(),
+                    output: Some("<pre>foo</pre>\n".to_string()),
+                },
+                MarkdownBlock {
+                    heading: Some("Tabs".to_string()),
+                    info: "example rust".to_string(),
+                    line: 16,
+                    contents: "```\nnested\n```\n".to_string(),
+                    output: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn does_not_pair_output_across_headings() {
+        let contents = "```input\na\n```\n# Heading\n```output\nb\n```\n";
+
+        let blocks = blocks(contents, "input");
+
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].heading, None);
+        assert_eq!(blocks[0].output, None);
+    }
+
+    #[test]
+    fn correctly_parses_atx_headings() {
+        assert_eq!(atx_heading("# Heading"), Some("Heading"));
+        assert_eq!(atx_heading("   ### Heading ###"), Some("Heading"));
+        assert_eq!(atx_heading("## Heading#"), Some("Heading#"));
+        assert_eq!(atx_heading("#"), Some(""));
+        assert_eq!(atx_heading("#Heading"), None);
+        assert_eq!(atx_heading("    # Heading"), None);
+        assert_eq!(atx_heading("####### Heading"), None);
+    }
+}
@@ -0,0 +1,149 @@
+use std::{
+    collections::BTreeMap,
+    path::{Component, Path, PathBuf},
+};
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Ident, LitStr};
+
+use crate::file_name_to_valid_identifier;
+
+/// Characters which start the non-literal part of a glob pattern.
+const GLOB_META_CHARS: &[char] = &['*', '?', '[', ']', '{', '}'];
+
+/// Returns the directory which nested modules are relative to. This is the deepest directory containing the literal
+/// prefixes of all (non-negated) glob patterns, e.g. `<root>/fixtures` for `fixtures/**/*.txt`.
+pub fn base_dir(root_dir: &Path, globs: &[LitStr]) -> PathBuf {
+    let mut base_dir: Option<PathBuf> = None;
+    for glob in globs {
+        let glob = glob.value();
+        if glob.starts_with('!') {
+            continue;
+        }
+        let mut components = glob.split('/').collect::<Vec<_>>();
+        // The last component matches the fixtures themselves, even if it's a literal
+        components.pop();
+        let prefix = components
+            .into_iter()
+            .take_while(|component| !component.contains(GLOB_META_CHARS))
+            .collect::<PathBuf>();
+        let glob_base_dir = root_dir.join(prefix);
+        base_dir = Some(match base_dir {
+            Some(base_dir) => common_ancestor(&base_dir, &glob_base_dir),
+            None => glob_base_dir,
+        });
+    }
+    base_dir.unwrap_or_else(|| root_dir.to_path_buf())
+}
+
+fn common_ancestor(a: &Path, b: &Path) -> PathBuf {
+    a.components()
+        .zip(b.components())
+        .take_while(|(a, b)| a == b)
+        .map(|(component, _)| component)
+        .collect()
+}
+
+/// Returns the modules which mirror the directories between `base_dir` and the fixture at `path`.
+pub fn module_path(path: &Path, base_dir: &Path, span: Span) -> Vec<Ident> {
+    let Some(parent) = path.parent() else {
+        return Vec::new();
+    };
+    let Ok(relative_dir) = parent.strip_prefix(base_dir) else {
+        return Vec::new();
+    };
+    relative_dir
+        .components()
+        .filter_map(|component| match component {
+            Component::Normal(dir_name) => Some(Ident::new(
+                &file_name_to_valid_identifier(&dir_name.to_string_lossy()),
+                span,
+            )),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The tokens of an expansion, and the modules it's nested in.
+type NestedExpansion<'a> = (&'a [Ident], &'a TokenStream);
+
+/// Emits the expansions, nesting each in the modules of its module path.
+pub fn nested_tokens<'a>(expansions: impl IntoIterator<Item = NestedExpansion<'a>>) -> TokenStream {
+    let mut tokens = TokenStream::new();
+    let mut modules: BTreeMap<String, (&Ident, Vec<NestedExpansion>)> = BTreeMap::new();
+    for (module_path, expansion_tokens) in expansions {
+        match module_path.split_first() {
+            None => tokens.extend(expansion_tokens.clone()),
+            Some((module, module_path)) => {
+                modules
+                    .entry(module.to_string())
+                    .or_insert_with(|| (module, Vec::new()))
+                    .1
+                    .push((module_path, expansion_tokens));
+            }
+        }
+    }
+    for (module, expansions) in modules.into_values() {
+        let module_tokens = nested_tokens(expansions);
+        tokens.extend(quote! {
+            pub mod #module {
+                use super::*;
+
+                #module_tokens
+            }
+        });
+    }
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lit_strs(globs: &[&str]) -> Vec<LitStr> {
+        globs
+            .iter()
+            .map(|glob| LitStr::new(glob, Span::call_site()))
+            .collect()
+    }
+
+    #[test]
+    fn correctly_computes_base_dir() {
+        let root_dir = Path::new("/root");
+
+        assert_eq!(
+            base_dir(root_dir, &lit_strs(&["fixtures/**/*.txt"])),
+            Path::new("/root/fixtures")
+        );
+        assert_eq!(
+            base_dir(root_dir, &lit_strs(&["fixtures/parser/*/*.txt"])),
+            Path::new("/root/fixtures/parser")
+        );
+        assert_eq!(
+            base_dir(
+                root_dir,
+                &lit_strs(&[
+                    "fixtures/parser/*.txt",
+                    "fixtures/lexer/*.txt",
+                    "!fixtures/*.txt"
+                ])
+            ),
+            Path::new("/root/fixtures")
+        );
+        assert_eq!(base_dir(root_dir, &lit_strs(&["*.txt"])), root_dir);
+    }
+
+    #[test]
+    fn correctly_computes_module_path() {
+        let module_path = module_path(
+            Path::new("/root/fixtures/parser/ok-cases/a.txt"),
+            Path::new("/root/fixtures"),
+            Span::call_site(),
+        );
+
+        assert_eq!(module_path.len(), 2);
+        assert_eq!(module_path[0], "parser");
+        assert_eq!(module_path[1], "ok_cases");
+    }
+}
@@ -1,25 +1,76 @@
 use syn::{
     ext::IdentExt as _,
     parse::{Parse, ParseStream},
-    Ident, Token,
+    token, Expr, Ident, LitBool, LitStr, Token,
 };
 
 use super::{
-    legacy_ignore_config::LegacyIgnoreConfig, option_assignment::OptionAssignment, paths::Paths,
-    spanned::Spanned as _,
+    companions::Companions, compare::Compare, format::Format, kind::Kind,
+    legacy_ignore_config::LegacyIgnoreConfig, name_template::NameTemplate,
+    option_assignment::OptionAssignment, path_style::PathStyle, paths::Paths, root::Root,
+    spanned::Spanned as _, split::Split, test_attrs::TestAttrs, values::Values,
 };
 
 pub struct Args {
     pub include: Paths,
+    /// Further lists of paths, which are bound to the following parameters. A test is generated for each combination
+    /// of fixtures.
+    pub product: Vec<Paths>,
     pub ignore: Option<LegacyIgnoreConfig>,
+    pub format: Option<Format>,
+    pub embed: Option<LitBool>,
+    pub root: Option<Root>,
+    pub path_style: Option<PathStyle>,
+    pub kind: Option<Kind>,
+    pub companions: Option<Companions>,
+    pub normalize: Option<Expr>,
+    pub test_attrs: Option<TestAttrs>,
+    pub nested: Option<LitBool>,
+    pub name: Option<NameTemplate>,
+    pub module: Option<LitStr>,
+    pub split: Option<Split>,
+    pub markdown_blocks: Option<LitStr>,
+    pub rows: Option<LitBool>,
+    pub row_name: Option<LitStr>,
+    pub values: Option<Values>,
+    pub compare: Option<Compare>,
 }
 
 impl Parse for Args {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let include: Paths = input.parse()?;
-        input.parse::<Option<Token![,]>>()?;
+        let mut comma = input.parse::<Option<Token![,]>>()?;
+
+        let mut product = Vec::new();
+        while input.peek(token::Bracket) {
+            if comma.is_none() {
+                return Err(syn::Error::new(
+                    input.span(),
+                    "Expected ',' between lists of paths.",
+                ));
+            }
+            product.push(input.parse::<Paths>()?);
+            comma = input.parse::<Option<Token![,]>>()?;
+        }
 
         let mut ignore = None;
+        let mut format = None;
+        let mut embed = None;
+        let mut root = None;
+        let mut path_style = None;
+        let mut kind = None;
+        let mut companions = None;
+        let mut normalize = None;
+        let mut test_attrs = None;
+        let mut nested = None;
+        let mut name = None;
+        let mut module = None;
+        let mut split = None;
+        let mut markdown_blocks = None;
+        let mut rows = None;
+        let mut row_name = None;
+        let mut values = None;
+        let mut compare = None;
 
         while !input.is_empty() {
             if input.peek(Ident::peek_any) {
@@ -33,6 +84,159 @@ impl Parse for Args {
                         }
                         ignore = Some(ignore_option_assignment.into_ignore_config());
                     }
+                    OptionAssignment::Format(format_option_assignment) => {
+                        if format.is_some() {
+                            return Err(syn::Error::new(
+                                format_option_assignment.span(),
+                                "Duplicate format assignment",
+                            ));
+                        }
+                        format = Some(format_option_assignment.into_value());
+                    }
+                    OptionAssignment::Embed(embed_option_assignment) => {
+                        if embed.is_some() {
+                            return Err(syn::Error::new(
+                                embed_option_assignment.span(),
+                                "Duplicate embed assignment",
+                            ));
+                        }
+                        embed = Some(embed_option_assignment.into_value());
+                    }
+                    OptionAssignment::Root(root_option_assignment) => {
+                        if root.is_some() {
+                            return Err(syn::Error::new(
+                                root_option_assignment.span(),
+                                "Duplicate root assignment",
+                            ));
+                        }
+                        root = Some(root_option_assignment.into_value());
+                    }
+                    OptionAssignment::PathStyle(path_style_option_assignment) => {
+                        if path_style.is_some() {
+                            return Err(syn::Error::new(
+                                path_style_option_assignment.span(),
+                                "Duplicate path style assignment",
+                            ));
+                        }
+                        path_style = Some(path_style_option_assignment.into_value());
+                    }
+                    OptionAssignment::Kind(kind_option_assignment) => {
+                        if kind.is_some() {
+                            return Err(syn::Error::new(
+                                kind_option_assignment.span(),
+                                "Duplicate kind assignment",
+                            ));
+                        }
+                        kind = Some(kind_option_assignment.into_value());
+                    }
+                    OptionAssignment::Companions(companions_option_assignment) => {
+                        if companions.is_some() {
+                            return Err(syn::Error::new(
+                                companions_option_assignment.span(),
+                                "Duplicate companions assignment",
+                            ));
+                        }
+                        companions = Some(companions_option_assignment.into_value());
+                    }
+                    OptionAssignment::Normalize(normalize_option_assignment) => {
+                        if normalize.is_some() {
+                            return Err(syn::Error::new(
+                                normalize_option_assignment.span(),
+                                "Duplicate normalize assignment",
+                            ));
+                        }
+                        normalize = Some(normalize_option_assignment.into_value());
+                    }
+                    OptionAssignment::TestAttrs(test_attrs_option_assignment) => {
+                        if test_attrs.is_some() {
+                            return Err(syn::Error::new(
+                                test_attrs_option_assignment.span(),
+                                "Duplicate test_attrs assignment",
+                            ));
+                        }
+                        test_attrs = Some(test_attrs_option_assignment.into_value());
+                    }
+                    OptionAssignment::Nested(nested_option_assignment) => {
+                        if nested.is_some() {
+                            return Err(syn::Error::new(
+                                nested_option_assignment.span(),
+                                "Duplicate nested assignment",
+                            ));
+                        }
+                        nested = Some(nested_option_assignment.into_value());
+                    }
+                    OptionAssignment::Name(name_option_assignment) => {
+                        if name.is_some() {
+                            return Err(syn::Error::new(
+                                name_option_assignment.span(),
+                                "Duplicate name assignment",
+                            ));
+                        }
+                        name = Some(name_option_assignment.into_value());
+                    }
+                    OptionAssignment::Module(module_option_assignment) => {
+                        if module.is_some() {
+                            return Err(syn::Error::new(
+                                module_option_assignment.span(),
+                                "Duplicate module assignment",
+                            ));
+                        }
+                        module = Some(module_option_assignment.into_value());
+                    }
+                    OptionAssignment::Split(split_option_assignment) => {
+                        if split.is_some() {
+                            return Err(syn::Error::new(
+                                split_option_assignment.span(),
+                                "Duplicate split assignment",
+                            ));
+                        }
+                        split = Some(split_option_assignment.into_value());
+                    }
+                    OptionAssignment::MarkdownBlocks(markdown_blocks_option_assignment) => {
+                        if markdown_blocks.is_some() {
+                            return Err(syn::Error::new(
+                                markdown_blocks_option_assignment.span(),
+                                "Duplicate markdown_blocks assignment",
+                            ));
+                        }
+                        markdown_blocks = Some(markdown_blocks_option_assignment.into_value());
+                    }
+                    OptionAssignment::Rows(rows_option_assignment) => {
+                        if rows.is_some() {
+                            return Err(syn::Error::new(
+                                rows_option_assignment.span(),
+                                "Duplicate rows assignment",
+                            ));
+                        }
+                        rows = Some(rows_option_assignment.into_value());
+                    }
+                    OptionAssignment::RowName(row_name_option_assignment) => {
+                        if row_name.is_some() {
+                            return Err(syn::Error::new(
+                                row_name_option_assignment.span(),
+                                "Duplicate row_name assignment",
+                            ));
+                        }
+                        row_name = Some(row_name_option_assignment.into_value());
+                    }
+                    OptionAssignment::Values(values_option_assignment) => {
+                        if values.is_some() {
+                            return Err(syn::Error::new(
+                                values_option_assignment.span(),
+                                "Duplicate values assignment",
+                            ));
+                        }
+                        values = Some(values_option_assignment);
+                    }
+                    OptionAssignment::Compare(compare_option_assignment) => {
+                        if compare.is_some() {
+                            return Err(syn::Error::new(
+                                compare_option_assignment.span(),
+                                "Duplicate compare assignment",
+                            ));
+                        }
+                        compare = Some(compare_option_assignment.into_value());
+                    }
                 }
             }
             if input.is_empty() {
@@ -40,13 +244,37 @@ impl Parse for Args {
             }
             input.parse::<Token![,]>()?;
         }
-        Ok(Args { include, ignore })
+        Ok(Args {
+            include,
+            product,
+            ignore,
+            format,
+            embed,
+            root,
+            path_style,
+            kind,
+            companions,
+            normalize,
+            test_attrs,
+            nested,
+            name,
+            module,
+            split,
+            markdown_blocks,
+            rows,
+            row_name,
+            values,
+            compare,
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parse::{
+        format::FormatKind, kind::FixtureKind, path_style::PathStyleKind, root::RootKind,
+    };
 
     #[test]
     fn correctly_parses_anonymous_include() {
@@ -146,4 +374,354 @@ mod tests {
 
         assert!(result.is_err());
     }
+
+    #[test]
+    fn correctly_parses_format() {
+        let input = r#"["fixtures/*.json"], format = "json""#;
+        let args: Args = syn::parse_str(input).expect("Failed to parse args");
+
+        assert!(args.format.is_some());
+        assert_eq!(args.format.unwrap().kind(), FormatKind::Json);
+    }
+
+    #[test]
+    fn returns_error_on_duplicate_format_assignments() {
+        let input = r#"
+            ["fixtures/*.json"],
+            format = "json",
+            format = "toml",
+        "#;
+        let result = syn::parse_str::<Args>(input);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn correctly_parses_embed() {
+        let input = r#"["fixtures/*.txt"], embed = true"#;
+        let args: Args = syn::parse_str(input).expect("Failed to parse args");
+
+        assert!(args.embed.is_some());
+        assert!(args.embed.unwrap().value());
+    }
+
+    #[test]
+    fn returns_error_on_duplicate_embed_assignments() {
+        let input = r#"
+            ["fixtures/*.txt"],
+            embed = true,
+            embed = false,
+        "#;
+        let result = syn::parse_str::<Args>(input);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn correctly_parses_root() {
+        let input = r#"["fixtures/*.txt"], root = "cwd""#;
+        let args: Args = syn::parse_str(input).expect("Failed to parse args");
+
+        assert!(args.root.is_some());
+        assert_eq!(args.root.unwrap().kind(), RootKind::Cwd);
+    }
+
+    #[test]
+    fn returns_error_on_duplicate_root_assignments() {
+        let input = r#"
+            ["fixtures/*.txt"],
+            root = "cwd",
+            root = "manifest",
+        "#;
+        let result = syn::parse_str::<Args>(input);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn correctly_parses_path_style() {
+        let input = r#"["fixtures/*.txt"], path_style = "manifest""#;
+        let args: Args = syn::parse_str(input).expect("Failed to parse args");
+
+        assert!(args.path_style.is_some());
+        assert_eq!(args.path_style.unwrap().kind(), PathStyleKind::Manifest);
+    }
+
+    #[test]
+    fn returns_error_on_duplicate_path_style_assignments() {
+        let input = r#"
+            ["fixtures/*.txt"],
+            path_style = "manifest",
+            path_style = "env",
+        "#;
+        let result = syn::parse_str::<Args>(input);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn correctly_parses_kind() {
+        let input = r#"["fixtures/*"], kind = "any""#;
+        let args: Args = syn::parse_str(input).expect("Failed to parse args");
+
+        assert!(args.kind.is_some());
+        assert_eq!(args.kind.unwrap().kind(), FixtureKind::Any);
+    }
+
+    #[test]
+    fn returns_error_on_duplicate_kind_assignments() {
+        let input = r#"
+            ["fixtures/*"],
+            kind = "file",
+            kind = "dir",
+        "#;
+        let result = syn::parse_str::<Args>(input);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn correctly_parses_companions() {
+        let input = r#"["fixtures/*.rs"], companions = ["stderr", "stdout?"]"#;
+        let args: Args = syn::parse_str(input).expect("Failed to parse args");
+
+        assert!(args.companions.is_some());
+        assert_eq!(args.companions.unwrap().companions().len(), 2);
+    }
+
+    #[test]
+    fn returns_error_on_duplicate_companions_assignments() {
+        let input = r#"
+            ["fixtures/*.rs"],
+            companions = ["stderr"],
+            companions = ["stdout"],
+        "#;
+        let result = syn::parse_str::<Args>(input);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn correctly_parses_normalize() {
+        let input = r#"["fixtures/*"], normalize = Normalizer::standard().replace("a", "b"), kind = "file""#;
+        let args: Args = syn::parse_str(input).expect("Failed to parse args");
+
+        assert!(args.normalize.is_some());
+        assert!(args.kind.is_some());
+    }
+
+    #[test]
+    fn returns_error_on_duplicate_normalize_assignments() {
+        let input = r#"
+            ["fixtures/*"],
+            normalize = normalizer(),
+            normalize = normalizer(),
+        "#;
+        let result = syn::parse_str::<Args>(input);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn correctly_parses_test_attrs() {
+        let input = r#"["fixtures/*"], test_attrs = [my_crate::test]"#;
+        let args: Args = syn::parse_str(input).expect("Failed to parse args");
+
+        assert!(args.test_attrs.is_some());
+        assert_eq!(args.test_attrs.unwrap().paths().len(), 1);
+    }
+
+    #[test]
+    fn returns_error_on_duplicate_test_attrs_assignments() {
+        let input = r#"
+            ["fixtures/*"],
+            test_attrs = [my_crate::test],
+            test_attrs = [other_crate::test],
+        "#;
+        let result = syn::parse_str::<Args>(input);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn correctly_parses_nested() {
+        let input = r#"["fixtures/**/*.txt"], nested = true"#;
+        let args: Args = syn::parse_str(input).expect("Failed to parse args");
+
+        assert!(args.nested.is_some());
+        assert!(args.nested.unwrap().value());
+    }
+
+    #[test]
+    fn returns_error_on_duplicate_nested_assignments() {
+        let input = r#"
+            ["fixtures/**/*.txt"],
+            nested = true,
+            nested = false,
+        "#;
+        let result = syn::parse_str::<Args>(input);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn correctly_parses_name_and_module() {
+        let input = r#"["fixtures/*.txt"], name = "{dir}_{stem}", module = "cases""#;
+        let args: Args = syn::parse_str(input).expect("Failed to parse args");
+
+        assert!(args.name.is_some());
+        assert_eq!(args.module.unwrap().value(), "cases");
+    }
+
+    #[test]
+    fn returns_error_on_duplicate_name_assignments() {
+        let input = r#"
+            ["fixtures/*.txt"],
+            name = "{stem}",
+            name = "{file}",
+        "#;
+        let result = syn::parse_str::<Args>(input);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn returns_error_on_duplicate_module_assignments() {
+        let input = r#"
+            ["fixtures/*.txt"],
+            module = "a",
+            module = "b",
+        "#;
+        let result = syn::parse_str::<Args>(input);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn correctly_parses_split() {
+        let input = r#"["fixtures/*.txt"], split = regex("^=== (.*) ===$")"#;
+        let args: Args = syn::parse_str(input).expect("Failed to parse args");
+
+        assert!(args.split.is_some());
+    }
+
+    #[test]
+    fn returns_error_on_duplicate_split_assignments() {
+        let input = r#"
+            ["fixtures/*.txt"],
+            split = "---",
+            split = "===",
+        "#;
+        let result = syn::parse_str::<Args>(input);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn correctly_parses_markdown_blocks() {
+        let input = r#"["spec/*.md"], markdown_blocks = "example""#;
+        let args: Args = syn::parse_str(input).expect("Failed to parse args");
+
+        assert_eq!(args.markdown_blocks.unwrap().value(), "example");
+    }
+
+    #[test]
+    fn returns_error_on_duplicate_markdown_blocks_assignments() {
+        let input = r#"
+            ["spec/*.md"],
+            markdown_blocks = "example",
+            markdown_blocks = "input",
+        "#;
+        let result = syn::parse_str::<Args>(input);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn correctly_parses_rows() {
+        let input = r#"["cases/*.csv"], rows = true, row_name = "name""#;
+        let args: Args = syn::parse_str(input).expect("Failed to parse args");
+
+        assert!(args.rows.unwrap().value());
+        assert_eq!(args.row_name.unwrap().value(), "name");
+    }
+
+    #[test]
+    fn returns_error_on_duplicate_row_name_assignments() {
+        let input = r#"
+            ["cases/*.csv"],
+            rows = true,
+            row_name = "name",
+            row_name = "input",
+        "#;
+        let result = syn::parse_str::<Args>(input);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn correctly_parses_multiple_includes() {
+        let input = r#"["configs/*.toml"], ["inputs/*.txt", "inputs/*.data"], embed = false"#;
+        let args: Args = syn::parse_str(input).expect("Failed to parse args");
+
+        assert_eq!(args.include.paths().len(), 1);
+        assert_eq!(args.product.len(), 1);
+        assert_eq!(args.product[0].paths().len(), 2);
+        assert_eq!(args.product[0].paths()[1].value(), "inputs/*.data");
+        assert!(args.embed.is_some());
+    }
+
+    #[test]
+    fn returns_error_on_missing_comma_between_includes() {
+        let input = r#"["configs/*.toml"] ["inputs/*.txt"]"#;
+        let result = syn::parse_str::<Args>(input);
+
+        assert_eq!(
+            result.err().unwrap().to_string(),
+            "Expected ',' between lists of paths."
+        );
+    }
+
+    #[test]
+    fn correctly_parses_values() {
+        let input = r#"["inputs/*.rs"], values(opt = [0, 1, 2, 3]), embed = false"#;
+        let args: Args = syn::parse_str(input).expect("Failed to parse args");
+
+        let values = args.values.expect("Expected values");
+        assert_eq!(values.lists().len(), 1);
+        assert_eq!(values.lists()[0].param, "opt");
+        assert_eq!(values.lists()[0].values.len(), 4);
+    }
+
+    #[test]
+    fn returns_error_on_duplicate_values_assignments() {
+        let input = r#"
+            ["inputs/*.rs"],
+            values(opt = [0, 1]),
+            values(debug = [true, false]),
+        "#;
+        let result = syn::parse_str::<Args>(input);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn correctly_parses_compare() {
+        let input = r#"["corpus/*"], compare = [fast::parse, reference::parse]"#;
+        let args: Args = syn::parse_str(input).expect("Failed to parse args");
+
+        assert_eq!(args.compare.unwrap().paths().len(), 2);
+    }
+
+    #[test]
+    fn returns_error_on_duplicate_compare_assignments() {
+        let input = r#"
+            ["corpus/*"],
+            compare = [fast::parse, reference::parse],
+            compare = [a, b],
+        "#;
+        let result = syn::parse_str::<Args>(input);
+
+        assert!(result.is_err());
+    }
 }
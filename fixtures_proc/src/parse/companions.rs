@@ -0,0 +1,148 @@
+use proc_macro2::Span;
+use syn::{
+    parse::{Parse, ParseStream},
+    Expr, ExprArray, Lit, LitStr,
+};
+
+use super::spanned::Spanned;
+
+/// A file which sits next to a fixture, and shares its stem.
+pub struct Companion {
+    lit_str: LitStr,
+    extension: String,
+    required: bool,
+}
+
+impl Companion {
+    fn new(lit_str: LitStr) -> syn::Result<Self> {
+        let value = lit_str.value();
+        let (extension, required) = match value.strip_suffix('?') {
+            Some(extension) => (extension, false),
+            None => (value.as_str(), true),
+        };
+        let extension = extension.trim_start_matches('.');
+        if extension.is_empty() || extension.contains(['/', '\\', '*', '?']) {
+            return Err(syn::Error::new(
+                lit_str.span(),
+                "Invalid companion. Expected a file extension, such as 'stderr' or 'stderr?'.",
+            ));
+        }
+        Ok(Companion {
+            lit_str,
+            extension: extension.to_string(),
+            required,
+        })
+    }
+
+    /// The extension of the companion file, without a leading `.`.
+    pub fn extension(&self) -> &str {
+        &self.extension
+    }
+
+    /// Whether the companion file must exist for every fixture. Optional companions are suffixed with `?`.
+    pub fn required(&self) -> bool {
+        self.required
+    }
+}
+
+impl Spanned for Companion {
+    fn span(&self) -> Span {
+        self.lit_str.span()
+    }
+}
+
+pub struct Companions {
+    span: Span,
+    companions: Vec<Companion>,
+}
+
+impl Parse for Companions {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let expr_array = ExprArray::parse(input)?;
+        let span = expr_array.span();
+
+        let mut companions: Vec<Companion> = Vec::with_capacity(expr_array.elems.len());
+
+        for elem in expr_array.elems {
+            let Expr::Lit(expr_lit) = elem else {
+                return Err(syn::Error::new(elem.span(), "Expected a string literal"));
+            };
+            let Lit::Str(lit_str) = expr_lit.lit else {
+                return Err(syn::Error::new(
+                    expr_lit.span(),
+                    "Expected a string literal",
+                ));
+            };
+            let companion = Companion::new(lit_str)?;
+            if companions
+                .iter()
+                .any(|existing| existing.extension == companion.extension)
+            {
+                return Err(syn::Error::new(
+                    companion.span(),
+                    format!("Duplicate companion '{}'", companion.extension),
+                ));
+            }
+            companions.push(companion);
+        }
+
+        Ok(Companions { span, companions })
+    }
+}
+
+impl Companions {
+    pub fn companions(&self) -> &Vec<Companion> {
+        &self.companions
+    }
+}
+
+impl Spanned for Companions {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn correctly_parses_required_companions() {
+        let input = r#"["stderr", "stdout"]"#;
+        let companions: Companions = syn::parse_str(input).expect("Failed to parse companions");
+
+        assert_eq!(companions.companions.len(), 2);
+        assert_eq!(companions.companions[0].extension(), "stderr");
+        assert!(companions.companions[0].required());
+        assert_eq!(companions.companions[1].extension(), "stdout");
+        assert!(companions.companions[1].required());
+    }
+
+    #[test]
+    fn correctly_parses_optional_companions() {
+        let input = r#"["stderr?", ".args?"]"#;
+        let companions: Companions = syn::parse_str(input).expect("Failed to parse companions");
+
+        assert_eq!(companions.companions.len(), 2);
+        assert_eq!(companions.companions[0].extension(), "stderr");
+        assert!(!companions.companions[0].required());
+        assert_eq!(companions.companions[1].extension(), "args");
+        assert!(!companions.companions[1].required());
+    }
+
+    #[test]
+    fn returns_error_on_invalid_companion() {
+        for input in [r#"["*.stderr"]"#, r#"["out/stderr"]"#, r#"["?"]"#, r#"[1]"#] {
+            let result = syn::parse_str::<Companions>(input);
+
+            assert!(result.is_err(), "Expected an error for {input}");
+        }
+    }
+
+    #[test]
+    fn returns_error_on_duplicate_companion() {
+        let result = syn::parse_str::<Companions>(r#"["stderr", "stderr?"]"#);
+
+        assert!(result.is_err());
+    }
+}
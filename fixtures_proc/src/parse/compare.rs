@@ -0,0 +1,89 @@
+use proc_macro2::Span;
+use syn::{
+    parse::{Parse, ParseStream},
+    Expr, ExprArray, Path,
+};
+
+use super::spanned::Spanned;
+
+/// The implementations compared by the `compare` option, e.g. `[fast::parse, reference::parse]`.
+pub struct Compare {
+    span: Span,
+    paths: Vec<Path>,
+}
+
+impl Parse for Compare {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let expr_array = ExprArray::parse(input)?;
+        let span = expr_array.span();
+
+        let mut paths = Vec::with_capacity(expr_array.elems.len());
+
+        for elem in expr_array.elems {
+            match elem {
+                Expr::Path(expr_path) if expr_path.qself.is_none() => paths.push(expr_path.path),
+                elem => {
+                    return Err(syn::Error::new(
+                        elem.span(),
+                        "Expected the path of a function, such as `reference::parse`",
+                    ))
+                }
+            }
+        }
+
+        if paths.len() < 2 {
+            return Err(syn::Error::new(
+                span,
+                "Expected at least two functions to compare",
+            ));
+        }
+
+        Ok(Compare { span, paths })
+    }
+}
+
+impl Compare {
+    pub fn paths(&self) -> &Vec<Path> {
+        &self.paths
+    }
+}
+
+impl Spanned for Compare {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::ToTokens;
+
+    use super::*;
+
+    #[test]
+    fn correctly_parses_compare() {
+        let input = r#"[fast::parse, reference::parse, parse]"#;
+        let compare: Compare = syn::parse_str(input).expect("Failed to parse compare");
+
+        let paths = compare
+            .paths()
+            .iter()
+            .map(|path| path.to_token_stream().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(paths, ["fast :: parse", "reference :: parse", "parse"]);
+    }
+
+    #[test]
+    fn returns_error_on_invalid_compare() {
+        for input in [
+            r#"[]"#,
+            r#"[parse]"#,
+            r#"["parse", other]"#,
+            r#"[parse(), other]"#,
+        ] {
+            let result = syn::parse_str::<Compare>(input);
+
+            assert!(result.is_err(), "Expected an error for {input}");
+        }
+    }
+}
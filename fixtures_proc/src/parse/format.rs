@@ -0,0 +1,45 @@
+use super::lit_str_enum::lit_str_enum;
+
+lit_str_enum! {
+    pub enum FormatKind {
+        /// Chooses the format based on the file extension.
+        Auto = "auto",
+        Json = "json",
+        Toml = "toml",
+        Yaml = "yaml",
+        Ron = "ron",
+    }
+
+    pub struct Format;
+
+    error = "Invalid format. Expected 'auto', 'json', 'toml', 'yaml' or 'ron'.";
+}
+
+impl Format {
+    /// Returns an error if the cargo feature of `fixtures` which enables the format is disabled.
+    pub fn check_feature(&self) -> syn::Result<()> {
+        let (enabled, features) = match self.kind {
+            FormatKind::Auto => (
+                cfg!(any(
+                    feature = "json",
+                    feature = "toml",
+                    feature = "yaml",
+                    feature = "ron"
+                )),
+                "one of the 'json', 'toml', 'yaml' or 'ron' features",
+            ),
+            FormatKind::Json => (cfg!(feature = "json"), "the 'json' feature"),
+            FormatKind::Toml => (cfg!(feature = "toml"), "the 'toml' feature"),
+            FormatKind::Yaml => (cfg!(feature = "yaml"), "the 'yaml' feature"),
+            FormatKind::Ron => (cfg!(feature = "ron"), "the 'ron' feature"),
+        };
+        if enabled {
+            Ok(())
+        } else {
+            Err(syn::Error::new(
+                self.span,
+                format!("This format requires {features} of fixtures to be enabled."),
+            ))
+        }
+    }
+}
@@ -0,0 +1,297 @@
+use std::marker::PhantomData;
+
+use proc_macro2::Span;
+use regex::Regex;
+use syn::{
+    parse::{Parse, ParseStream},
+    AttrStyle, Attribute, Ident, LitStr, MacroDelimiter, Meta, Token,
+};
+
+use crate::{parse::spanned::Spanned, utils::attribute::attribute_path_is};
+
+use super::assignment::Assignment;
+
+/// An attribute which applies to the fixtures matching a glob, such as `#[fixtures::ignore("<glob>")]`, and which
+/// optionally takes a value, such as `#[fixtures::ignore(paths = "<glob>", reason = "<reason>")]`.
+pub trait GlobAttributeKind {
+    /// The path of the attribute.
+    const PATH: [&'static str; 2];
+    /// The name of the optional field.
+    const FIELD: &'static str;
+
+    fn validate(_value: &LitStr) -> syn::Result<()> {
+        Ok(())
+    }
+}
+
+/// `#[fixtures::ignore]`, whose value is the reason for ignoring the tests.
+pub struct Ignore;
+
+/// `#[fixtures::should_panic]`, whose value is the expected panic message.
+pub struct ShouldPanic;
+
+/// `#[fixtures::expect_err]`, whose value is a regex which the error must match.
+pub struct ExpectErr;
+
+impl GlobAttributeKind for Ignore {
+    const PATH: [&'static str; 2] = ["fixtures", "ignore"];
+    const FIELD: &'static str = "reason";
+}
+
+impl GlobAttributeKind for ShouldPanic {
+    const PATH: [&'static str; 2] = ["fixtures", "should_panic"];
+    const FIELD: &'static str = "expected";
+}
+
+impl GlobAttributeKind for ExpectErr {
+    const PATH: [&'static str; 2] = ["fixtures", "expect_err"];
+    const FIELD: &'static str = "matches";
+
+    fn validate(value: &LitStr) -> syn::Result<()> {
+        match Regex::new(&value.value()) {
+            Ok(_) => Ok(()),
+            Err(err) => Err(syn::Error::new(
+                value.span(),
+                format!("Invalid regex: {err}"),
+            )),
+        }
+    }
+}
+
+pub type IgnoreAttribute = GlobAttribute<Ignore>;
+pub type ShouldPanicAttribute = GlobAttribute<ShouldPanic>;
+pub type ExpectErrAttribute = GlobAttribute<ExpectErr>;
+
+pub struct GlobArgs<K: GlobAttributeKind> {
+    pub paths: LitStr,
+    pub value: Option<LitStr>,
+    kind: PhantomData<K>,
+}
+
+impl<K: GlobAttributeKind> Parse for GlobArgs<K> {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Ident) {
+            Self::parse_fields(input)
+        } else {
+            Self::parse_literal(input)
+        }
+    }
+}
+
+impl<K: GlobAttributeKind> GlobArgs<K> {
+    fn parse_literal(input: ParseStream) -> syn::Result<Self> {
+        let path = input.parse::<LitStr>()?;
+        if !input.is_empty() {
+            return Err(syn::Error::new(input.span(), "Unexpected token."));
+        }
+        Ok(Self {
+            paths: path,
+            value: None,
+            kind: PhantomData,
+        })
+    }
+
+    fn parse_fields(input: ParseStream) -> syn::Result<Self> {
+        let error_span = input.span();
+        let mut paths = None;
+        let mut value = None;
+
+        while !input.is_empty() {
+            let assignment = input.parse::<Assignment<LitStr>>()?;
+            let ident = assignment.ident().clone();
+
+            let field = if ident == "paths" {
+                &mut paths
+            } else if ident == K::FIELD {
+                &mut value
+            } else {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    format!(
+                        "Invalid field identifier. Expected 'paths' or '{}'.",
+                        K::FIELD
+                    ),
+                ));
+            };
+            if field.is_some() {
+                return Err(syn::Error::new(ident.span(), "Duplicate assignment."));
+            }
+            *field = Some(assignment.into_value());
+
+            if input.is_empty() {
+                break;
+            }
+
+            input.parse::<Token![,]>()?;
+        }
+
+        if let Some(value) = &value {
+            K::validate(value)?;
+        }
+
+        Ok(Self {
+            paths: paths
+                .ok_or_else(|| syn::Error::new(error_span, "The 'paths' field is missing."))?,
+            value,
+            kind: PhantomData,
+        })
+    }
+}
+
+pub struct GlobAttribute<K: GlobAttributeKind> {
+    span: Span,
+    pub args: GlobArgs<K>,
+}
+
+impl<K: GlobAttributeKind> GlobAttribute<K> {
+    pub fn try_from_attribute(attr: &Attribute) -> syn::Result<Option<Self>> {
+        if attr.style != AttrStyle::Outer {
+            return Ok(None);
+        }
+
+        let Meta::List(meta) = &attr.meta else {
+            return Ok(None);
+        };
+
+        if !attribute_path_is(&meta.path, K::PATH) {
+            return Ok(None);
+        }
+
+        if !matches!(meta.delimiter, MacroDelimiter::Paren(_)) {
+            return Err(syn::Error::new(
+                meta.delimiter.span().span(),
+                "Expected parentheses",
+            ));
+        }
+
+        Ok(Some(Self {
+            span: attr.span(),
+            args: syn::parse2::<GlobArgs<K>>(meta.tokens.clone())?,
+        }))
+    }
+}
+
+impl<K: GlobAttributeKind> Spanned for GlobAttribute<K> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::{parse_quote, Attribute};
+
+    use super::{ExpectErr, GlobArgs, GlobAttribute, Ignore, ShouldPanic};
+
+    #[test]
+    fn correctly_parses_literal_form() {
+        let args = syn::parse_str::<GlobArgs<Ignore>>(r#""fixtures/*.txt""#)
+            .expect("Failed to parse args");
+        assert_eq!(args.paths.value(), "fixtures/*.txt");
+        assert!(args.value.is_none());
+
+        let args = syn::parse_str::<GlobArgs<ShouldPanic>>(r#""fixtures/*.txt""#)
+            .expect("Failed to parse args");
+        assert_eq!(args.paths.value(), "fixtures/*.txt");
+        assert!(args.value.is_none());
+
+        let args = syn::parse_str::<GlobArgs<ExpectErr>>(r#""fixtures/*.txt""#)
+            .expect("Failed to parse args");
+        assert_eq!(args.paths.value(), "fixtures/*.txt");
+        assert!(args.value.is_none());
+    }
+
+    #[test]
+    fn correctly_parses_field_form() {
+        let args =
+            syn::parse_str::<GlobArgs<Ignore>>(r#"paths = "fixtures/*.txt", reason = "slow""#)
+                .expect("Failed to parse args");
+        assert_eq!(args.paths.value(), "fixtures/*.txt");
+        assert_eq!(args.value.unwrap().value(), "slow");
+
+        let args = syn::parse_str::<GlobArgs<ShouldPanic>>(
+            r#"expected = "boom", paths = "fixtures/*.txt""#,
+        )
+        .expect("Failed to parse args");
+        assert_eq!(args.paths.value(), "fixtures/*.txt");
+        assert_eq!(args.value.unwrap().value(), "boom");
+
+        let args =
+            syn::parse_str::<GlobArgs<ExpectErr>>(r#"paths = "fixtures/*.txt", matches = "^bad""#)
+                .expect("Failed to parse args");
+        assert_eq!(args.paths.value(), "fixtures/*.txt");
+        assert_eq!(args.value.unwrap().value(), "^bad");
+
+        let args = syn::parse_str::<GlobArgs<Ignore>>(r#"paths = "fixtures/*.txt""#)
+            .expect("Failed to parse args");
+        assert_eq!(args.paths.value(), "fixtures/*.txt");
+        assert!(args.value.is_none());
+    }
+
+    #[test]
+    fn correctly_parses_attribute() {
+        let attr: Attribute = parse_quote!(#[fixtures::should_panic("fixtures/*.txt")]);
+        let parsed = GlobAttribute::<ShouldPanic>::try_from_attribute(&attr)
+            .expect("Failed to parse attribute")
+            .expect("Expected attribute to match");
+        assert_eq!(parsed.args.paths.value(), "fixtures/*.txt");
+
+        assert!(GlobAttribute::<Ignore>::try_from_attribute(&attr)
+            .expect("Failed to parse attribute")
+            .is_none());
+    }
+
+    #[test]
+    fn returns_error_on_duplicate_paths() {
+        let result = syn::parse_str::<GlobArgs<Ignore>>(r#"paths = "a.txt", paths = "b.txt""#);
+        assert_eq!(result.err().unwrap().to_string(), "Duplicate assignment.");
+    }
+
+    #[test]
+    fn returns_error_on_duplicate_value() {
+        let result = syn::parse_str::<GlobArgs<ShouldPanic>>(
+            r#"paths = "a.txt", expected = "a", expected = "b""#,
+        );
+        assert_eq!(result.err().unwrap().to_string(), "Duplicate assignment.");
+    }
+
+    #[test]
+    fn returns_error_on_missing_paths() {
+        let result = syn::parse_str::<GlobArgs<Ignore>>(r#"reason = "slow""#);
+        assert_eq!(
+            result.err().unwrap().to_string(),
+            "The 'paths' field is missing."
+        );
+
+        let result = syn::parse_str::<GlobArgs<ExpectErr>>(r#"matches = "bad""#);
+        assert_eq!(
+            result.err().unwrap().to_string(),
+            "The 'paths' field is missing."
+        );
+    }
+
+    #[test]
+    fn returns_error_on_field_of_other_attribute() {
+        let result = syn::parse_str::<GlobArgs<ShouldPanic>>(r#"paths = "a.txt", reason = "slow""#);
+        assert_eq!(
+            result.err().unwrap().to_string(),
+            "Invalid field identifier. Expected 'paths' or 'expected'."
+        );
+    }
+
+    #[test]
+    fn returns_error_on_trailing_tokens_after_literal() {
+        let result = syn::parse_str::<GlobArgs<Ignore>>(r#""a.txt" "b.txt""#);
+        assert_eq!(result.err().unwrap().to_string(), "Unexpected token.");
+    }
+
+    #[test]
+    fn returns_error_on_invalid_regex() {
+        let result = syn::parse_str::<GlobArgs<ExpectErr>>(r#"paths = "a.txt", matches = "(""#);
+        assert!(result
+            .err()
+            .unwrap()
+            .to_string()
+            .starts_with("Invalid regex: "));
+    }
+}
@@ -0,0 +1,16 @@
+use super::lit_str_enum::lit_str_enum;
+
+lit_str_enum! {
+    pub enum FixtureKind {
+        /// Generates a test for each matched file.
+        File = "file",
+        /// Generates a test for each matched directory.
+        Dir = "dir",
+        /// Generates a test for each matched file or directory.
+        Any = "any",
+    }
+
+    pub struct Kind;
+
+    error = "Invalid kind. Expected 'file', 'dir' or 'any'.";
+}
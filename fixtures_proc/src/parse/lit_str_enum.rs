@@ -0,0 +1,125 @@
+/// Defines an option which takes one of a fixed set of string literals, such as `kind = "dir"`. This expands to an
+/// enum of the values, and a struct which parses the literal and keeps its span.
+macro_rules! lit_str_enum {
+    (
+        $(#[$kind_meta:meta])*
+        pub enum $kind:ident {
+            $($(#[$variant_meta:meta])* $variant:ident = $value:literal,)*
+        }
+
+        pub struct $name:ident;
+
+        error = $error:literal;
+    ) => {
+        $(#[$kind_meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum $kind {
+            $($(#[$variant_meta])* $variant,)*
+        }
+
+        pub struct $name {
+            span: ::proc_macro2::Span,
+            kind: $kind,
+        }
+
+        impl ::syn::parse::Parse for $name {
+            fn parse(input: ::syn::parse::ParseStream) -> ::syn::Result<Self> {
+                let lit_str = input.parse::<::syn::LitStr>()?;
+                let kind = match lit_str.value().as_str() {
+                    $($value => $kind::$variant,)*
+                    _ => return Err(::syn::Error::new(lit_str.span(), $error)),
+                };
+                Ok($name {
+                    span: lit_str.span(),
+                    kind,
+                })
+            }
+        }
+
+        impl $name {
+            pub fn kind(&self) -> $kind {
+                self.kind
+            }
+        }
+
+        impl $crate::parse::spanned::Spanned for $name {
+            fn span(&self) -> ::proc_macro2::Span {
+                self.span
+            }
+        }
+    };
+}
+
+pub(crate) use lit_str_enum;
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{format::Format, kind::Kind, path_style::PathStyle, root::Root};
+
+    /// Parses `value` as the given option, returning the `Debug` output of the parsed kind.
+    fn parse(option: &str, value: &str) -> syn::Result<String> {
+        let input = format!("{value:?}");
+        match option {
+            "format" => syn::parse_str::<Format>(&input).map(|v| format!("{:?}", v.kind())),
+            "kind" => syn::parse_str::<Kind>(&input).map(|v| format!("{:?}", v.kind())),
+            "path_style" => syn::parse_str::<PathStyle>(&input).map(|v| format!("{:?}", v.kind())),
+            "root" => syn::parse_str::<Root>(&input).map(|v| format!("{:?}", v.kind())),
+            _ => unreachable!("unknown option {option}"),
+        }
+    }
+
+    #[test]
+    fn correctly_parses_values() {
+        for (option, value, expected) in [
+            ("format", "auto", "Auto"),
+            ("format", "json", "Json"),
+            ("format", "toml", "Toml"),
+            ("format", "yaml", "Yaml"),
+            ("format", "ron", "Ron"),
+            ("kind", "file", "File"),
+            ("kind", "dir", "Dir"),
+            ("kind", "any", "Any"),
+            ("path_style", "absolute", "Absolute"),
+            ("path_style", "manifest", "Manifest"),
+            ("path_style", "env", "Env"),
+            ("root", "workspace", "Workspace"),
+            ("root", "manifest", "Manifest"),
+            ("root", "cwd", "Cwd"),
+        ] {
+            let kind = parse(option, value)
+                .unwrap_or_else(|err| panic!("Failed to parse {option} {value:?}: {err}"));
+
+            assert_eq!(kind, expected);
+        }
+    }
+
+    #[test]
+    fn returns_error_on_invalid_value() {
+        for (option, value, message) in [
+            (
+                "format",
+                "xml",
+                "Invalid format. Expected 'auto', 'json', 'toml', 'yaml' or 'ron'.",
+            ),
+            (
+                "kind",
+                "symlink",
+                "Invalid kind. Expected 'file', 'dir' or 'any'.",
+            ),
+            (
+                "path_style",
+                "relative",
+                "Invalid path style. Expected 'absolute', 'manifest' or 'env'.",
+            ),
+            (
+                "root",
+                "home",
+                "Invalid root. Expected 'workspace', 'manifest' or 'cwd'.",
+            ),
+        ] {
+            let err = parse(option, value).expect_err("Expected an error");
+
+            assert_eq!(err.to_string(), message);
+        }
+    }
+}
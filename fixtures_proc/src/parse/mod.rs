@@ -1,8 +1,18 @@
 pub mod args;
 pub mod assignment;
-pub mod ignore_args;
-pub mod ignore_attribute;
+pub mod companions;
+pub mod compare;
+pub mod format;
+pub mod glob_attribute;
+pub mod kind;
 pub mod legacy_ignore_config;
+pub mod lit_str_enum;
+pub mod name_template;
 pub mod option_assignment;
+pub mod path_style;
 pub mod paths;
+pub mod root;
 pub mod spanned;
+pub mod split;
+pub mod test_attrs;
+pub mod values;
@@ -0,0 +1,198 @@
+use proc_macro2::Span;
+use syn::{
+    parse::{Parse, ParseStream},
+    LitStr,
+};
+
+use super::spanned::Spanned;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Placeholder {
+    /// `{file}` - the file name of the fixture, e.g. `a.txt`.
+    File,
+    /// `{stem}` - the file name without its extension, e.g. `a`.
+    Stem,
+    /// `{ext}` - the extension of the file, e.g. `txt`.
+    Ext,
+    /// `{dir}` - the name of the directory containing the fixture, e.g. `ok`.
+    Dir,
+    /// `{rel_path}` - the path of the fixture relative to the literal prefix of the glob patterns, e.g. `parser/ok/a.txt`.
+    RelPath,
+    /// `{1}`, `{2}`, ... - the text matched by the nth wildcard of the glob pattern which matched the fixture.
+    Capture(usize),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TemplatePart {
+    Literal(String),
+    Placeholder(Placeholder),
+}
+
+/// The values substituted for the placeholders of a name template.
+pub struct NameContext<'a> {
+    pub file: &'a str,
+    pub stem: &'a str,
+    pub ext: &'a str,
+    pub dir: &'a str,
+    pub rel_path: &'a str,
+    pub captures: Option<&'a [String]>,
+}
+
+/// A template for the names of the generated tests, e.g. `"{dir}_{stem}"`.
+pub struct NameTemplate {
+    span: Span,
+    parts: Vec<TemplatePart>,
+}
+
+impl Parse for NameTemplate {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let lit_str = input.parse::<LitStr>()?;
+        let span = lit_str.span();
+        let template = lit_str.value();
+
+        let mut parts = Vec::new();
+        let mut rest = template.as_str();
+        while let Some(start) = rest.find(['{', '}']) {
+            if rest[start..].starts_with('}') {
+                return Err(syn::Error::new(span, "Unexpected '}' in name template"));
+            }
+            if start > 0 {
+                parts.push(TemplatePart::Literal(rest[..start].to_string()));
+            }
+            let Some(end) = rest[start..].find('}') else {
+                return Err(syn::Error::new(span, "Unclosed '{' in name template"));
+            };
+            let placeholder = match &rest[start + 1..start + end] {
+                "file" => Placeholder::File,
+                "stem" => Placeholder::Stem,
+                "ext" => Placeholder::Ext,
+                "dir" => Placeholder::Dir,
+                "rel_path" => Placeholder::RelPath,
+                capture => match capture.parse::<usize>() {
+                    Ok(index) if index > 0 => Placeholder::Capture(index),
+                    _ => {
+                        return Err(syn::Error::new(
+                            span,
+                            format!(
+                                "Invalid placeholder '{{{capture}}}'. Expected '{{file}}', '{{stem}}', '{{ext}}', '{{dir}}', '{{rel_path}}' or a glob capture such as '{{1}}'."
+                            ),
+                        ))
+                    }
+                },
+            };
+            parts.push(TemplatePart::Placeholder(placeholder));
+            rest = &rest[start + end + 1..];
+        }
+        if !rest.is_empty() {
+            parts.push(TemplatePart::Literal(rest.to_string()));
+        }
+
+        Ok(NameTemplate { span, parts })
+    }
+}
+
+impl NameTemplate {
+    /// Checks whether the template refers to the captures of the glob patterns.
+    pub fn has_captures(&self) -> bool {
+        self.parts
+            .iter()
+            .any(|part| matches!(part, TemplatePart::Placeholder(Placeholder::Capture(_))))
+    }
+
+    /// Substitutes the placeholders of the template. The result isn't necessarily a valid identifier.
+    pub fn render(&self, context: &NameContext) -> syn::Result<String> {
+        let mut name = String::new();
+        for part in &self.parts {
+            match part {
+                TemplatePart::Literal(literal) => name.push_str(literal),
+                TemplatePart::Placeholder(placeholder) => name.push_str(match placeholder {
+                    Placeholder::File => context.file,
+                    Placeholder::Stem => context.stem,
+                    Placeholder::Ext => context.ext,
+                    Placeholder::Dir => context.dir,
+                    Placeholder::RelPath => context.rel_path,
+                    Placeholder::Capture(index) => {
+                        let captures = context.captures.unwrap_or_default();
+                        let Some(capture) = captures.get(index - 1) else {
+                            return Err(syn::Error::new(
+                                self.span,
+                                format!(
+                                    "The name template refers to glob capture {{{index}}}, but the glob pattern which matched '{}' only has {} wildcard(s)",
+                                    context.rel_path,
+                                    captures.len()
+                                ),
+                            ));
+                        };
+                        capture
+                    }
+                }),
+            }
+        }
+        Ok(name)
+    }
+}
+
+impl Spanned for NameTemplate {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context(captures: &[String]) -> NameContext<'_> {
+        NameContext {
+            file: "a.txt",
+            stem: "a",
+            ext: "txt",
+            dir: "ok",
+            rel_path: "parser/ok/a.txt",
+            captures: Some(captures),
+        }
+    }
+
+    #[test]
+    fn correctly_renders_placeholders() {
+        let template: NameTemplate = syn::parse_str(r#""{dir}_{stem}.{ext}:{file}:{rel_path}""#)
+            .expect("Failed to parse name template");
+
+        let name = template.render(&context(&[])).unwrap();
+
+        assert_eq!(name, "ok_a.txt:a.txt:parser/ok/a.txt");
+        assert!(!template.has_captures());
+    }
+
+    #[test]
+    fn correctly_renders_captures() {
+        let template: NameTemplate =
+            syn::parse_str(r#""case_{2}_{1}""#).expect("Failed to parse name template");
+
+        let name = template
+            .render(&context(&["parser".to_string(), "a".to_string()]))
+            .unwrap();
+
+        assert_eq!(name, "case_a_parser");
+        assert!(template.has_captures());
+    }
+
+    #[test]
+    fn returns_error_on_missing_capture() {
+        let template: NameTemplate =
+            syn::parse_str(r#""{3}""#).expect("Failed to parse name template");
+
+        let result = template.render(&context(&["a".to_string()]));
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn returns_error_on_invalid_template() {
+        for input in [r#""{unknown}""#, r#""{0}""#, r#""{stem""#, r#""stem}""#] {
+            let result = syn::parse_str::<NameTemplate>(input);
+
+            assert!(result.is_err(), "Expected an error for {input}");
+        }
+    }
+}
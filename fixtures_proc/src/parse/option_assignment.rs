@@ -1,13 +1,34 @@
 use proc_macro2::Span;
 use syn::{
     parse::{Parse, ParseStream},
-    Ident, Token,
+    Expr, Ident, LitBool, LitStr, Token,
 };
 
-use super::{legacy_ignore_config::LegacyIgnoreConfig, spanned::Spanned};
+use super::{
+    companions::Companions, compare::Compare, format::Format, kind::Kind,
+    legacy_ignore_config::LegacyIgnoreConfig, name_template::NameTemplate, path_style::PathStyle,
+    root::Root, spanned::Spanned, split::Split, test_attrs::TestAttrs, values::Values,
+};
 
 pub enum OptionAssignment {
     Ignore(IgnoreOptionAssignment),
+    Format(ValueOptionAssignment<Format>),
+    Embed(ValueOptionAssignment<LitBool>),
+    Root(ValueOptionAssignment<Root>),
+    PathStyle(ValueOptionAssignment<PathStyle>),
+    Kind(ValueOptionAssignment<Kind>),
+    Companions(ValueOptionAssignment<Companions>),
+    Normalize(ValueOptionAssignment<Expr>),
+    TestAttrs(ValueOptionAssignment<TestAttrs>),
+    Nested(ValueOptionAssignment<LitBool>),
+    Name(ValueOptionAssignment<NameTemplate>),
+    Module(ValueOptionAssignment<LitStr>),
+    Split(ValueOptionAssignment<Split>),
+    MarkdownBlocks(ValueOptionAssignment<LitStr>),
+    Rows(ValueOptionAssignment<LitBool>),
+    RowName(ValueOptionAssignment<LitStr>),
+    Values(Values),
+    Compare(ValueOptionAssignment<Compare>),
 }
 
 impl Parse for OptionAssignment {
@@ -18,6 +39,57 @@ impl Parse for OptionAssignment {
                 "ignore" => Ok(OptionAssignment::Ignore(
                     IgnoreOptionAssignment::parse_from_ident(input, ident)?,
                 )),
+                "format" => Ok(OptionAssignment::Format(
+                    ValueOptionAssignment::parse_from_ident(input, ident)?,
+                )),
+                "embed" => Ok(OptionAssignment::Embed(
+                    ValueOptionAssignment::parse_from_ident(input, ident)?,
+                )),
+                "root" => Ok(OptionAssignment::Root(
+                    ValueOptionAssignment::parse_from_ident(input, ident)?,
+                )),
+                "path_style" => Ok(OptionAssignment::PathStyle(
+                    ValueOptionAssignment::parse_from_ident(input, ident)?,
+                )),
+                "kind" => Ok(OptionAssignment::Kind(
+                    ValueOptionAssignment::parse_from_ident(input, ident)?,
+                )),
+                "companions" => Ok(OptionAssignment::Companions(
+                    ValueOptionAssignment::parse_from_ident(input, ident)?,
+                )),
+                "normalize" => Ok(OptionAssignment::Normalize(
+                    ValueOptionAssignment::parse_from_ident(input, ident)?,
+                )),
+                "test_attrs" => Ok(OptionAssignment::TestAttrs(
+                    ValueOptionAssignment::parse_from_ident(input, ident)?,
+                )),
+                "nested" => Ok(OptionAssignment::Nested(
+                    ValueOptionAssignment::parse_from_ident(input, ident)?,
+                )),
+                "name" => Ok(OptionAssignment::Name(
+                    ValueOptionAssignment::parse_from_ident(input, ident)?,
+                )),
+                "module" => Ok(OptionAssignment::Module(
+                    ValueOptionAssignment::parse_from_ident(input, ident)?,
+                )),
+                "split" => Ok(OptionAssignment::Split(
+                    ValueOptionAssignment::parse_from_ident(input, ident)?,
+                )),
+                "markdown_blocks" => Ok(OptionAssignment::MarkdownBlocks(
+                    ValueOptionAssignment::parse_from_ident(input, ident)?,
+                )),
+                "rows" => Ok(OptionAssignment::Rows(
+                    ValueOptionAssignment::parse_from_ident(input, ident)?,
+                )),
+                "row_name" => Ok(OptionAssignment::RowName(
+                    ValueOptionAssignment::parse_from_ident(input, ident)?,
+                )),
+                "values" => Ok(OptionAssignment::Values(Values::parse_from_ident(
+                    input, ident,
+                )?)),
+                "compare" => Ok(OptionAssignment::Compare(
+                    ValueOptionAssignment::parse_from_ident(input, ident)?,
+                )),
                 _ => Err(syn::Error::new(ident.span(), "Invalid option")),
             };
         }
@@ -53,9 +125,41 @@ impl Spanned for IgnoreOptionAssignment {
     }
 }
 
+/// An option assignment of the form `<ident> = <value>`.
+pub struct ValueOptionAssignment<TVal: Parse + Spanned> {
+    span: Span,
+    value: TVal,
+}
+
+impl<TVal: Parse + Spanned> ValueOptionAssignment<TVal> {
+    pub fn into_value(self) -> TVal {
+        self.value
+    }
+
+    fn parse_from_ident(input: ParseStream, ident: Ident) -> syn::Result<Self> {
+        input.parse::<Token![=]>()?;
+        let value: TVal = input.parse()?;
+        let span = ident
+            .span()
+            .join(value.span())
+            // On non-nightly compilers, join will always return None
+            .unwrap_or_else(|| value.span());
+        Ok(ValueOptionAssignment { span, value })
+    }
+}
+
+impl<TVal: Parse + Spanned> Spanned for ValueOptionAssignment<TVal> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parse::{
+        format::FormatKind, kind::FixtureKind, path_style::PathStyleKind, root::RootKind,
+    };
 
     #[test]
     fn correctly_parses_ignore_option_assignment_without_paths() {
@@ -63,8 +167,9 @@ mod tests {
         let option_assignment: OptionAssignment =
             syn::parse_str(input).expect("Failed to parse option assignment");
 
-        assert!(matches!(option_assignment, OptionAssignment::Ignore(_)));
-        let OptionAssignment::Ignore(ignore_option_assignment) = option_assignment;
+        let OptionAssignment::Ignore(ignore_option_assignment) = option_assignment else {
+            panic!("Expected ignore option assignment");
+        };
         assert!(ignore_option_assignment.config.paths().paths().is_empty());
     }
 
@@ -74,8 +179,9 @@ mod tests {
         let option_assignment: OptionAssignment =
             syn::parse_str(input).expect("Failed to parse option assignment");
 
-        assert!(matches!(option_assignment, OptionAssignment::Ignore(_)));
-        let OptionAssignment::Ignore(ignore_option_assignment) = option_assignment;
+        let OptionAssignment::Ignore(ignore_option_assignment) = option_assignment else {
+            panic!("Expected ignore option assignment");
+        };
         assert_eq!(ignore_option_assignment.config.paths().paths().len(), 1);
         assert_eq!(
             ignore_option_assignment.config.paths().paths()[0]
@@ -84,4 +190,229 @@ mod tests {
             "fixtures/*.ignore.txt"
         );
     }
+
+    #[test]
+    fn correctly_parses_format_option_assignment() {
+        let input = r#"format = "toml""#;
+        let option_assignment: OptionAssignment =
+            syn::parse_str(input).expect("Failed to parse option assignment");
+
+        let OptionAssignment::Format(format_option_assignment) = option_assignment else {
+            panic!("Expected format option assignment");
+        };
+        assert_eq!(
+            format_option_assignment.into_value().kind(),
+            FormatKind::Toml
+        );
+    }
+
+    #[test]
+    fn correctly_parses_embed_option_assignment() {
+        let input = r#"embed = true"#;
+        let option_assignment: OptionAssignment =
+            syn::parse_str(input).expect("Failed to parse option assignment");
+
+        let OptionAssignment::Embed(embed_option_assignment) = option_assignment else {
+            panic!("Expected embed option assignment");
+        };
+        assert!(embed_option_assignment.into_value().value());
+    }
+
+    #[test]
+    fn correctly_parses_root_option_assignment() {
+        let input = r#"root = "workspace""#;
+        let option_assignment: OptionAssignment =
+            syn::parse_str(input).expect("Failed to parse option assignment");
+
+        let OptionAssignment::Root(root_option_assignment) = option_assignment else {
+            panic!("Expected root option assignment");
+        };
+        assert_eq!(
+            root_option_assignment.into_value().kind(),
+            RootKind::Workspace
+        );
+    }
+
+    #[test]
+    fn correctly_parses_path_style_option_assignment() {
+        let input = r#"path_style = "env""#;
+        let option_assignment: OptionAssignment =
+            syn::parse_str(input).expect("Failed to parse option assignment");
+
+        let OptionAssignment::PathStyle(path_style_option_assignment) = option_assignment else {
+            panic!("Expected path style option assignment");
+        };
+        assert_eq!(
+            path_style_option_assignment.into_value().kind(),
+            PathStyleKind::Env
+        );
+    }
+
+    #[test]
+    fn correctly_parses_kind_option_assignment() {
+        let input = r#"kind = "dir""#;
+        let option_assignment: OptionAssignment =
+            syn::parse_str(input).expect("Failed to parse option assignment");
+
+        let OptionAssignment::Kind(kind_option_assignment) = option_assignment else {
+            panic!("Expected kind option assignment");
+        };
+        assert_eq!(kind_option_assignment.into_value().kind(), FixtureKind::Dir);
+    }
+
+    #[test]
+    fn correctly_parses_companions_option_assignment() {
+        let input = r#"companions = ["stderr", "args?"]"#;
+        let option_assignment: OptionAssignment =
+            syn::parse_str(input).expect("Failed to parse option assignment");
+
+        let OptionAssignment::Companions(companions_option_assignment) = option_assignment else {
+            panic!("Expected companions option assignment");
+        };
+        let companions = companions_option_assignment.into_value();
+        assert_eq!(companions.companions()[0].extension(), "stderr");
+        assert_eq!(companions.companions()[1].extension(), "args");
+    }
+
+    #[test]
+    fn correctly_parses_normalize_option_assignment() {
+        let input = r#"normalize = normalizer()"#;
+        let option_assignment: OptionAssignment =
+            syn::parse_str(input).expect("Failed to parse option assignment");
+
+        let OptionAssignment::Normalize(normalize_option_assignment) = option_assignment else {
+            panic!("Expected normalize option assignment");
+        };
+        assert!(matches!(
+            normalize_option_assignment.into_value(),
+            Expr::Call(_)
+        ));
+    }
+
+    #[test]
+    fn correctly_parses_test_attrs_option_assignment() {
+        let input = r#"test_attrs = [my_crate::test, other_test]"#;
+        let option_assignment: OptionAssignment =
+            syn::parse_str(input).expect("Failed to parse option assignment");
+
+        let OptionAssignment::TestAttrs(test_attrs_option_assignment) = option_assignment else {
+            panic!("Expected test_attrs option assignment");
+        };
+        assert_eq!(test_attrs_option_assignment.into_value().paths().len(), 2);
+    }
+
+    #[test]
+    fn correctly_parses_nested_option_assignment() {
+        let input = r#"nested = true"#;
+        let option_assignment: OptionAssignment =
+            syn::parse_str(input).expect("Failed to parse option assignment");
+
+        let OptionAssignment::Nested(nested_option_assignment) = option_assignment else {
+            panic!("Expected nested option assignment");
+        };
+        assert!(nested_option_assignment.into_value().value());
+    }
+
+    #[test]
+    fn correctly_parses_name_option_assignment() {
+        let input = r#"name = "case_{1}""#;
+        let option_assignment: OptionAssignment =
+            syn::parse_str(input).expect("Failed to parse option assignment");
+
+        let OptionAssignment::Name(name_option_assignment) = option_assignment else {
+            panic!("Expected name option assignment");
+        };
+        assert!(name_option_assignment.into_value().has_captures());
+    }
+
+    #[test]
+    fn correctly_parses_module_option_assignment() {
+        let input = r#"module = "cases""#;
+        let option_assignment: OptionAssignment =
+            syn::parse_str(input).expect("Failed to parse option assignment");
+
+        let OptionAssignment::Module(module_option_assignment) = option_assignment else {
+            panic!("Expected module option assignment");
+        };
+        assert_eq!(module_option_assignment.into_value().value(), "cases");
+    }
+
+    #[test]
+    fn correctly_parses_split_option_assignment() {
+        let input = r#"split = "---""#;
+        let option_assignment: OptionAssignment =
+            syn::parse_str(input).expect("Failed to parse option assignment");
+
+        let OptionAssignment::Split(split_option_assignment) = option_assignment else {
+            panic!("Expected split option assignment");
+        };
+        assert!(matches!(
+            split_option_assignment.into_value().delimiter(),
+            crate::parse::split::Delimiter::Literal(_)
+        ));
+    }
+
+    #[test]
+    fn correctly_parses_markdown_blocks_option_assignment() {
+        let input = r#"markdown_blocks = "example""#;
+        let option_assignment: OptionAssignment =
+            syn::parse_str(input).expect("Failed to parse option assignment");
+
+        let OptionAssignment::MarkdownBlocks(markdown_blocks_option_assignment) = option_assignment
+        else {
+            panic!("Expected markdown_blocks option assignment");
+        };
+        assert_eq!(
+            markdown_blocks_option_assignment.into_value().value(),
+            "example"
+        );
+    }
+
+    #[test]
+    fn correctly_parses_rows_option_assignment() {
+        let input = "rows = true";
+        let option_assignment: OptionAssignment =
+            syn::parse_str(input).expect("Failed to parse option assignment");
+
+        let OptionAssignment::Rows(rows_option_assignment) = option_assignment else {
+            panic!("Expected rows option assignment");
+        };
+        assert!(rows_option_assignment.into_value().value());
+    }
+
+    #[test]
+    fn correctly_parses_row_name_option_assignment() {
+        let input = r#"row_name = "name""#;
+        let option_assignment: OptionAssignment =
+            syn::parse_str(input).expect("Failed to parse option assignment");
+
+        let OptionAssignment::RowName(row_name_option_assignment) = option_assignment else {
+            panic!("Expected row_name option assignment");
+        };
+        assert_eq!(row_name_option_assignment.into_value().value(), "name");
+    }
+
+    #[test]
+    fn correctly_parses_values_option_assignment() {
+        let input = r#"values(opt = [0, 1], target = ["x86_64"])"#;
+        let option_assignment: OptionAssignment =
+            syn::parse_str(input).expect("Failed to parse option assignment");
+
+        let OptionAssignment::Values(values) = option_assignment else {
+            panic!("Expected values option assignment");
+        };
+        assert_eq!(values.lists().len(), 2);
+    }
+
+    #[test]
+    fn correctly_parses_compare_option_assignment() {
+        let input = "compare = [fast::parse, reference::parse]";
+        let option_assignment: OptionAssignment =
+            syn::parse_str(input).expect("Failed to parse option assignment");
+
+        let OptionAssignment::Compare(compare_option_assignment) = option_assignment else {
+            panic!("Expected compare option assignment");
+        };
+        assert_eq!(compare_option_assignment.into_value().paths().len(), 2);
+    }
 }
@@ -0,0 +1,16 @@
+use super::lit_str_enum::lit_str_enum;
+
+lit_str_enum! {
+    pub enum PathStyleKind {
+        /// Emits the absolute path of the fixture at compile time.
+        Absolute = "absolute",
+        /// Emits `concat!(env!("CARGO_MANIFEST_DIR"), "/<relative path>")`.
+        Manifest = "manifest",
+        /// Resolves the path at runtime, relative to `FIXTURES_ROOT` or `CARGO_MANIFEST_DIR`.
+        Env = "env",
+    }
+
+    pub struct PathStyle;
+
+    error = "Invalid path style. Expected 'absolute', 'manifest' or 'env'.";
+}
@@ -0,0 +1,16 @@
+use super::lit_str_enum::lit_str_enum;
+
+lit_str_enum! {
+    pub enum RootKind {
+        /// The root of the cargo workspace.
+        Workspace = "workspace",
+        /// The directory containing the `Cargo.toml` of the current crate.
+        Manifest = "manifest",
+        /// The current directory of the compiler process.
+        Cwd = "cwd",
+    }
+
+    pub struct Root;
+
+    error = "Invalid root. Expected 'workspace', 'manifest' or 'cwd'.";
+}
@@ -0,0 +1,113 @@
+use proc_macro2::Span;
+use regex::Regex;
+use syn::{
+    parenthesized,
+    parse::{Parse, ParseStream},
+    Ident, LitStr,
+};
+
+use super::spanned::Spanned;
+
+/// The lines which separate the sections of a fixture file.
+#[derive(Debug)]
+pub enum Delimiter {
+    /// `split = "---"` - a line which is equal to the literal, ignoring trailing whitespace.
+    Literal(String),
+    /// `split = regex("^=== (?<name>.*) ===$")` - a line which matches the regular expression. The section is named
+    /// after the `name` capture group, or the first capture group if there is no `name` group.
+    Regex(Regex),
+}
+
+pub struct Split {
+    span: Span,
+    delimiter: Delimiter,
+}
+
+impl Parse for Split {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(LitStr) {
+            let lit_str = input.parse::<LitStr>()?;
+            let literal = lit_str.value();
+            if literal.trim().is_empty() || literal.contains('\n') {
+                return Err(syn::Error::new(
+                    lit_str.span(),
+                    "Invalid delimiter. Expected a single, non-empty line, such as '---'.",
+                ));
+            }
+            return Ok(Split {
+                span: lit_str.span(),
+                delimiter: Delimiter::Literal(literal.trim_end().to_string()),
+            });
+        }
+
+        let ident = input.parse::<Ident>()?;
+        if ident != "regex" {
+            return Err(syn::Error::new(
+                ident.span(),
+                "Invalid delimiter. Expected a string literal, or a regular expression such as `regex(\"^=== (?<name>.*) ===$\")`.",
+            ));
+        }
+        let content;
+        parenthesized!(content in input);
+        let lit_str = content.parse::<LitStr>()?;
+        let regex = Regex::new(&lit_str.value()).map_err(|err| {
+            syn::Error::new(lit_str.span(), format!("Invalid regular expression: {err}"))
+        })?;
+        let span = ident
+            .span()
+            .join(lit_str.span())
+            // On non-nightly compilers, join will always return None
+            .unwrap_or_else(|| lit_str.span());
+        Ok(Split {
+            span,
+            delimiter: Delimiter::Regex(regex),
+        })
+    }
+}
+
+impl Split {
+    pub fn delimiter(&self) -> &Delimiter {
+        &self.delimiter
+    }
+}
+
+impl Spanned for Split {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn correctly_parses_literal_delimiter() {
+        let split: Split = syn::parse_str(r#""---  ""#).expect("Failed to parse split");
+
+        let Delimiter::Literal(literal) = split.delimiter() else {
+            panic!("Expected literal delimiter");
+        };
+        assert_eq!(literal, "---");
+    }
+
+    #[test]
+    fn correctly_parses_regex_delimiter() {
+        let split: Split =
+            syn::parse_str(r#"regex("^=== (?<name>.*) ===$")"#).expect("Failed to parse split");
+
+        let Delimiter::Regex(regex) = split.delimiter() else {
+            panic!("Expected regex delimiter");
+        };
+        assert!(regex.is_match("=== case ==="));
+    }
+
+    #[test]
+    fn returns_error_on_invalid_delimiter() {
+        for input in [r#""""#, r#""  ""#, r#"regex("(")"#, r#"glob("*")"#, "regex"] {
+            let result = syn::parse_str::<Split>(input);
+
+            assert!(result.is_err(), "Expected an error for {input}");
+        }
+    }
+}
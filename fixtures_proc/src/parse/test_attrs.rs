@@ -0,0 +1,79 @@
+use proc_macro2::Span;
+use syn::{
+    parse::{Parse, ParseStream},
+    Expr, ExprArray, Path,
+};
+
+use super::spanned::Spanned;
+
+pub struct TestAttrs {
+    span: Span,
+    paths: Vec<Path>,
+}
+
+impl Parse for TestAttrs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let expr_array = ExprArray::parse(input)?;
+        let span = expr_array.span();
+
+        let mut paths = Vec::with_capacity(expr_array.elems.len());
+
+        for elem in expr_array.elems {
+            match elem {
+                Expr::Path(expr_path) if expr_path.qself.is_none() => paths.push(expr_path.path),
+                elem => {
+                    return Err(syn::Error::new(
+                        elem.span(),
+                        "Expected the path of an attribute, such as `tokio::test`",
+                    ))
+                }
+            }
+        }
+
+        Ok(TestAttrs { span, paths })
+    }
+}
+
+impl TestAttrs {
+    pub fn paths(&self) -> &Vec<Path> {
+        &self.paths
+    }
+}
+
+impl Spanned for TestAttrs {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::ToTokens;
+
+    use super::*;
+
+    #[test]
+    fn correctly_parses_test_attrs() {
+        let input = r#"[my_test, my_crate::test, ::other_crate::test]"#;
+        let test_attrs: TestAttrs = syn::parse_str(input).expect("Failed to parse test attrs");
+
+        let paths = test_attrs
+            .paths()
+            .iter()
+            .map(|path| path.to_token_stream().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            ["my_test", "my_crate :: test", ":: other_crate :: test"]
+        );
+    }
+
+    #[test]
+    fn returns_error_on_invalid_test_attr() {
+        for input in [r#"["test"]"#, r#"[test()]"#, r#"[<T as Trait>::test]"#] {
+            let result = syn::parse_str::<TestAttrs>(input);
+
+            assert!(result.is_err(), "Expected an error for {input}");
+        }
+    }
+}
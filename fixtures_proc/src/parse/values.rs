@@ -0,0 +1,151 @@
+use proc_macro2::Span;
+use quote::ToTokens as _;
+use syn::{
+    parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Expr, ExprArray, Ident, Lit, Token,
+};
+
+use super::{assignment::Assignment, spanned::Spanned};
+
+/// The `values(<param> = [<value>, ...], ...)` option, which binds a list of values to each named parameter.
+pub struct Values {
+    span: Span,
+    lists: Vec<ValueList>,
+}
+
+pub struct ValueList {
+    pub param: Ident,
+    pub values: Vec<Expr>,
+}
+
+impl Values {
+    pub fn parse_from_ident(input: ParseStream, ident: Ident) -> syn::Result<Self> {
+        let content;
+        let paren = parenthesized!(content in input);
+        let assignments =
+            Punctuated::<Assignment<ExprArray>, Token![,]>::parse_terminated(&content)?;
+        if assignments.is_empty() {
+            return Err(syn::Error::new(
+                paren.span.join(),
+                "Expected a list of values for a parameter, such as `values(level = [1, 2])`",
+            ));
+        }
+
+        let mut lists: Vec<ValueList> = Vec::with_capacity(assignments.len());
+        for assignment in assignments {
+            let param = assignment.ident().clone();
+            if lists.iter().any(|list| list.param == param) {
+                return Err(syn::Error::new(
+                    param.span(),
+                    format!("Duplicate values for parameter `{param}`"),
+                ));
+            }
+            let expr_array = assignment.into_value();
+            if expr_array.elems.is_empty() {
+                return Err(syn::Error::new(
+                    expr_array.span(),
+                    "Expected at least one value",
+                ));
+            }
+            lists.push(ValueList {
+                param,
+                values: expr_array.elems.into_iter().collect(),
+            });
+        }
+
+        let span = ident
+            .span()
+            .join(paren.span.join())
+            // On non-nightly compilers, join will always return None
+            .unwrap_or_else(|| paren.span.join());
+        Ok(Values { span, lists })
+    }
+
+    pub fn lists(&self) -> &[ValueList] {
+        &self.lists
+    }
+}
+
+impl Spanned for Values {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl Parse for Values {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident = input.parse::<Ident>()?;
+        if ident != "values" {
+            return Err(syn::Error::new(ident.span(), "Expected `values`"));
+        }
+        Self::parse_from_ident(input, ident)
+    }
+}
+
+/// Returns how the value is referred to within test names. String and character literals are named by their value,
+/// and other expressions by their source, e.g. `O2` for `"O2"`, and `Level::O2` for `Level::O2`.
+pub fn value_name(value: &Expr) -> String {
+    match value {
+        Expr::Lit(expr_lit) => match &expr_lit.lit {
+            Lit::Str(lit_str) => lit_str.value(),
+            Lit::Char(lit_char) => lit_char.value().to_string(),
+            lit => lit.to_token_stream().to_string(),
+        },
+        value => value
+            .to_token_stream()
+            .to_string()
+            .split_whitespace()
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn correctly_parses_values() {
+        let input = r#"values(opt = [0, 1, 2], flag = ["a", "b"],)"#;
+        let values: Values = syn::parse_str(input).expect("Failed to parse values");
+
+        let lists = values.lists();
+        assert_eq!(lists.len(), 2);
+        assert_eq!(lists[0].param, "opt");
+        assert_eq!(lists[0].values.len(), 3);
+        assert_eq!(lists[1].param, "flag");
+        assert_eq!(value_name(&lists[1].values[1]), "b");
+    }
+
+    #[test]
+    fn correctly_names_values() {
+        for (input, expected) in [
+            ("42", "42"),
+            ("-1", "-1"),
+            ("'x'", "x"),
+            (r#""O2""#, "O2"),
+            ("Level::O2", "Level::O2"),
+            ("Some(1)", "Some(1)"),
+        ] {
+            let value = syn::parse_str::<Expr>(input).expect("Failed to parse value");
+
+            assert_eq!(value_name(&value), expected);
+        }
+    }
+
+    #[test]
+    fn returns_error_on_invalid_values() {
+        for input in [
+            "values()",
+            "values(opt = [])",
+            "values(opt = [1], opt = [2])",
+            "values(opt = 1)",
+            "values[opt = [1]]",
+        ] {
+            let result = syn::parse_str::<Values>(input);
+
+            assert!(result.is_err(), "Expected an error for {input}");
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use std::path::{Path, PathBuf};
+
+use crate::parse::root::RootKind;
+
+/// Returns the directory which glob patterns are resolved against.
+pub fn root_dir(kind: RootKind) -> PathBuf {
+    match kind {
+        RootKind::Workspace => workspace_dir(),
+        RootKind::Manifest => manifest_dir(),
+        RootKind::Cwd => current_dir(),
+    }
+}
+
+fn current_dir() -> PathBuf {
+    std::env::current_dir().expect("failed to get current directory")
+}
+
+pub fn manifest_dir() -> PathBuf {
+    std::env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(current_dir)
+}
+
+/// Returns the nearest ancestor of the manifest directory (including itself) with a `[workspace]` manifest, or the
+/// manifest directory if the crate isn't part of a workspace.
+fn workspace_dir() -> PathBuf {
+    let manifest_dir = manifest_dir();
+    manifest_dir
+        .ancestors()
+        .find(|dir| is_workspace_root(dir))
+        .map(Path::to_path_buf)
+        .unwrap_or(manifest_dir)
+}
+
+fn is_workspace_root(dir: &Path) -> bool {
+    std::fs::read_to_string(dir.join("Cargo.toml"))
+        .is_ok_and(|manifest| has_workspace_table(&manifest))
+}
+
+/// Checks whether the manifest has a `workspace` table. Manifests which can't be parsed are treated as not being a
+/// workspace root.
+fn has_workspace_table(manifest: &str) -> bool {
+    toml::from_str::<toml::Table>(manifest)
+        .is_ok_and(|manifest| manifest.get("workspace").is_some_and(toml::Value::is_table))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::has_workspace_table;
+
+    #[test]
+    fn correctly_detects_workspace_tables() {
+        for (manifest, expected) in [
+            ("[workspace]\nmembers = [\"a\"]\n", true),
+            ("[workspace] # the root\n", true),
+            ("[workspace.package]\nversion = \"1.0.0\"\n", true),
+            ("[package]\nname = \"a\"\n", false),
+            (
+                "[package]\nname = \"a\"\ndescription = \"\"\"\n[workspace]\n\"\"\"\n",
+                false,
+            ),
+            ("[workspace\n", false),
+        ] {
+            assert_eq!(has_workspace_table(manifest), expected, "{manifest}");
+        }
+    }
+}
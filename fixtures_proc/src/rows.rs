@@ -0,0 +1,393 @@
+use std::path::Path;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Type;
+
+/// The format of a table fixture, which is determined by its extension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RowsFormat {
+    /// `.csv` - comma-separated values, where the first record is the header.
+    Csv,
+    /// `.jsonl` or `.ndjson` - a JSON object or array on each line.
+    JsonLines,
+}
+
+impl RowsFormat {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "csv" => Some(RowsFormat::Csv),
+            "jsonl" | "ndjson" => Some(RowsFormat::JsonLines),
+            _ => None,
+        }
+    }
+}
+
+/// A row of a table fixture, read at compile time.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Row {
+    /// The 1-based line number the row starts on.
+    pub line: usize,
+    /// The text of the row, as it appears in the file.
+    pub source: String,
+    pub columns: Vec<String>,
+    /// The text of each field. For JSON Lines, strings are unquoted, and other values are kept as JSON.
+    pub fields: Vec<String>,
+}
+
+/// An error in a table fixture, and the 1-based line number it occurred on.
+#[derive(Debug, PartialEq, Eq)]
+pub struct RowError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl Row {
+    /// Returns the field of the given column, if the row has it.
+    pub fn field(&self, column: &str) -> Option<&str> {
+        self.columns
+            .iter()
+            .position(|name| name == column)
+            .map(|index| self.fields[index].as_str())
+    }
+
+    /// Returns an expression of the type of the fixture parameter, which converts the row using
+    /// `::fixtures::rows::FromRow` if the type implements it, and deserializes it otherwise.
+    pub fn to_tokens(
+        &self,
+        path_tokens: &TokenStream,
+        format: RowsFormat,
+        ty: Option<&Type>,
+    ) -> TokenStream {
+        let Row {
+            line,
+            source,
+            columns,
+            fields,
+        } = self;
+        let format = match format {
+            RowsFormat::Csv => quote!(::fixtures::rows::RowFormat::Csv),
+            RowsFormat::JsonLines => quote!(::fixtures::rows::RowFormat::JsonLines),
+        };
+        let row = quote! {
+            ::fixtures::Row::new(#path_tokens, #format, #line, &[#(#columns),*], &[#(#fields),*], #source)
+        };
+        match ty {
+            Some(ty) => quote! {{
+                use ::fixtures::__private::{DeserializeRow as _, LoadRow as _};
+                (&::fixtures::__private::RowLoader::<#ty>::new()).load(#row)
+            }},
+            None => row,
+        }
+    }
+
+    /// Checks that the fields of the row can be converted to a tuple type, so that rows which can't be converted are
+    /// reported at compile time. Only the number of fields, and fields of primitive types, can be checked.
+    pub fn check_fields(&self, ty: &Type) -> Result<(), String> {
+        let ty = match ty {
+            Type::Paren(paren) => &paren.elem,
+            ty => ty,
+        };
+        let Type::Tuple(tuple) = ty else {
+            return Ok(());
+        };
+        if tuple.elems.len() != self.fields.len() {
+            return Err(format!(
+                "Expected {} fields, but the row has {}",
+                tuple.elems.len(),
+                self.fields.len()
+            ));
+        }
+        for (index, (elem, field)) in tuple.elems.iter().zip(&self.fields).enumerate() {
+            if let Err(err) = check_field(elem, field) {
+                let column = match self.columns.get(index) {
+                    Some(column) => format!("'{column}'"),
+                    None => format!("{}", index + 1),
+                };
+                return Err(format!("Failed to parse column {column}: {err}"));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Checks that the field can be parsed as the type, if it's a primitive type, or an `Option` of one.
+fn check_field(ty: &Type, field: &str) -> Result<(), String> {
+    let Type::Path(type_path) = ty else {
+        return Ok(());
+    };
+    if type_path.qself.is_some() {
+        return Ok(());
+    }
+    if let Some(segment) = std_segment(&type_path.path, "option", "Option") {
+        let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+            return Ok(());
+        };
+        return match (args.args.first(), field) {
+            (_, "" | "null") => Ok(()),
+            (Some(syn::GenericArgument::Type(ty)), field) => check_field(ty, field),
+            _ => Ok(()),
+        };
+    }
+    let Some(segment) = std_segment(&type_path.path, "primitive", "") else {
+        return Ok(());
+    };
+    if !segment.arguments.is_none() {
+        return Ok(());
+    }
+
+    let ident = segment.ident.to_string();
+    let result = match ident.as_str() {
+        "bool" => parse_field::<bool>(field),
+        "char" => parse_field::<char>(field),
+        "f32" => parse_field::<f32>(field),
+        "f64" => parse_field::<f64>(field),
+        "i8" => parse_field::<i8>(field),
+        "i16" => parse_field::<i16>(field),
+        "i32" => parse_field::<i32>(field),
+        "i64" => parse_field::<i64>(field),
+        "i128" => parse_field::<i128>(field),
+        "isize" => parse_field::<isize>(field),
+        "u8" => parse_field::<u8>(field),
+        "u16" => parse_field::<u16>(field),
+        "u32" => parse_field::<u32>(field),
+        "u64" => parse_field::<u64>(field),
+        "u128" => parse_field::<u128>(field),
+        "usize" => parse_field::<usize>(field),
+        _ => Ok(()),
+    };
+    result.map_err(|err| format!("`{field}` isn't a valid `{ident}`: {err}"))
+}
+
+/// Returns the last segment of a path which names an item of the standard library, either on its own (`i64`) or by
+/// its full path (`std::primitive::i64`). If `name` isn't empty, the item must have that name. Other paths, such as
+/// type aliases or types of other crates, are only converted at runtime, so they aren't checked.
+fn std_segment<'a>(path: &'a syn::Path, module: &str, name: &str) -> Option<&'a syn::PathSegment> {
+    let segments = path.segments.iter().collect::<Vec<_>>();
+    let segment = match &segments[..] {
+        [segment] if path.leading_colon.is_none() => segment,
+        [krate, parent, segment]
+            if (krate.ident == "std" || krate.ident == "core")
+                && parent.ident == module
+                && krate.arguments.is_none()
+                && parent.arguments.is_none() =>
+        {
+            segment
+        }
+        _ => return None,
+    };
+    (name.is_empty() || segment.ident == name).then_some(*segment)
+}
+
+fn parse_field<T: std::str::FromStr>(field: &str) -> Result<(), String>
+where
+    T::Err: std::fmt::Display,
+{
+    field
+        .parse::<T>()
+        .map(|_| ())
+        .map_err(|err| err.to_string())
+}
+
+/// Parses the rows of a table fixture.
+pub fn parse(contents: &str, format: RowsFormat) -> Result<Vec<Row>, RowError> {
+    match format {
+        RowsFormat::Csv => parse_csv(contents),
+        RowsFormat::JsonLines => parse_json_lines(contents),
+    }
+}
+
+fn parse_csv(contents: &str) -> Result<Vec<Row>, RowError> {
+    let csv_error = |err: csv::Error| RowError {
+        line: err
+            .position()
+            .map_or(1, |position| position.line() as usize),
+        message: format!("Invalid CSV: {err}"),
+    };
+    // Records with the wrong number of fields are reported below, to include the expected number of fields
+    let mut reader = csv::ReaderBuilder::new()
+        .flexible(true)
+        .from_reader(contents.as_bytes());
+    let columns = reader
+        .headers()
+        .map_err(csv_error)?
+        .iter()
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+    let mut rows = Vec::new();
+    let mut record = csv::StringRecord::new();
+    while reader.read_record(&mut record).map_err(csv_error)? {
+        let position = record
+            .position()
+            .expect("records read from a reader should have a position");
+        // The position of a record can include the end of the previous line, and blank lines
+        let source = &contents[position.byte() as usize..reader.position().byte() as usize];
+        let skipped = &source[..source.len() - source.trim_start_matches(['\r', '\n']).len()];
+        let source = &source[skipped.len()..];
+        let line = position.line() as usize + skipped.matches('\n').count();
+        if record.len() != columns.len() {
+            return Err(RowError {
+                line,
+                message: format!(
+                    "Expected {} fields, but found {}",
+                    columns.len(),
+                    record.len()
+                ),
+            });
+        }
+        rows.push(Row {
+            line,
+            source: source.trim_end_matches(['\r', '\n']).to_string(),
+            columns: columns.clone(),
+            fields: record.iter().map(str::to_string).collect(),
+        });
+    }
+    Ok(rows)
+}
+
+fn parse_json_lines(contents: &str) -> Result<Vec<Row>, RowError> {
+    let mut rows = Vec::new();
+    for (index, source) in contents.lines().enumerate() {
+        let line = index + 1;
+        if source.trim().is_empty() {
+            continue;
+        }
+        let value = serde_json::from_str::<serde_json::Value>(source).map_err(|err| RowError {
+            line,
+            message: format!("Invalid JSON: {err}"),
+        })?;
+        let (columns, values) = match value {
+            serde_json::Value::Object(object) => object.into_iter().unzip(),
+            serde_json::Value::Array(array) => (Vec::new(), array),
+            _ => {
+                return Err(RowError {
+                    line,
+                    message: "Expected a JSON object or array".to_string(),
+                })
+            }
+        };
+        let fields = values
+            .into_iter()
+            .map(|value| match value {
+                serde_json::Value::String(string) => string,
+                value => value.to_string(),
+            })
+            .collect();
+        rows.push(Row {
+            line,
+            source: source.to_string(),
+            columns,
+            fields,
+        });
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn correctly_parses_csv() {
+        let contents = "input,expected\r\n1 + 2,3\n\n\"a, \"\"b\"\"\",\"multi\nline\"\n";
+
+        let rows = parse(contents, RowsFormat::Csv).expect("Failed to parse CSV");
+
+        assert_eq!(
+            rows,
+            [
+                Row {
+                    line: 2,
+                    source: "1 + 2,3".to_string(),
+                    columns: strings(&["input", "expected"]),
+                    fields: strings(&["1 + 2", "3"]),
+                },
+                Row {
+                    line: 4,
+                    source: "\"a, \"\"b\"\"\",\"multi\nline\"".to_string(),
+                    columns: strings(&["input", "expected"]),
+                    fields: strings(&["a, \"b\"", "multi\nline"]),
+                },
+            ]
+        );
+        assert_eq!(rows[1].field("expected"), Some("multi\nline"));
+    }
+
+    #[test]
+    fn returns_error_on_invalid_csv() {
+        for (contents, line) in [
+            ("a,b\n1,2\n1,2,3\n", 3),
+            ("a,b\n1,2\n\"1,2\n", 3),
+            ("a,b\r\n1,2\r\n\r\n3\r\n", 4),
+        ] {
+            let result = parse(contents, RowsFormat::Csv);
+
+            assert_eq!(
+                result.map_err(|err| err.line),
+                Err(line),
+                "Expected an error for {contents:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn correctly_parses_json_lines() {
+        let contents = "{\"input\": \"1 + 2\", \"expected\": 3}\n\n[true, null]\n";
+
+        let rows = parse(contents, RowsFormat::JsonLines).expect("Failed to parse JSON Lines");
+
+        assert_eq!(rows[0].line, 1);
+        assert_eq!(rows[0].columns, strings(&["input", "expected"]));
+        assert_eq!(rows[0].fields, strings(&["1 + 2", "3"]));
+        assert_eq!(rows[1].line, 3);
+        assert!(rows[1].columns.is_empty());
+        assert_eq!(rows[1].fields, strings(&["true", "null"]));
+    }
+
+    #[test]
+    fn returns_error_on_invalid_json_lines() {
+        for contents in ["{}\n{\n", "{}\n42\n"] {
+            let result = parse(contents, RowsFormat::JsonLines);
+
+            assert_eq!(
+                result.map_err(|err| err.line),
+                Err(2),
+                "Expected an error for {contents:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn correctly_checks_tuple_fields() {
+        let contents = "name,count,limit\nfirst,1,\nsecond,-1,\nthird,abc,\n";
+        let rows = parse(contents, RowsFormat::Csv).expect("Failed to parse CSV");
+
+        for (ty, expected) in [
+            ("(&str, i64, Option<u8>)", [true, true, false]),
+            ("(&str, u32, Option<u8>)", [true, false, false]),
+            ("(&str, Count, Option<u8>)", [true, true, true]),
+            (
+                "(&str, std::primitive::u32, core::option::Option<u8>)",
+                [true, false, false],
+            ),
+            ("(&str, my::u32, Option<u8>)", [true, true, true]),
+            ("(&str, ::u32, Option<u8>)", [true, true, true]),
+            ("(&str, i64)", [false, false, false]),
+            ("Case", [true, true, true]),
+        ] {
+            let ty = syn::parse_str::<Type>(ty).expect("Failed to parse type");
+
+            let results = rows.iter().map(|row| row.check_fields(&ty).is_ok());
+
+            assert_eq!(
+                results.collect::<Vec<_>>(),
+                expected,
+                "Unexpected result for {ty:?}"
+            );
+        }
+    }
+}
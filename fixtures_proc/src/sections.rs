@@ -0,0 +1,139 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::parse::split::Delimiter;
+
+/// A section of a fixture file which was split using the `split` option.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Section {
+    /// The name captured from the delimiter which starts the section, if any.
+    pub name: Option<String>,
+    /// The 1-based line number of the first line of the section.
+    pub line: usize,
+    pub text: String,
+}
+
+impl Section {
+    /// Returns an expression of type `::fixtures::Section`.
+    pub fn to_tokens(&self, path_tokens: &TokenStream) -> TokenStream {
+        let Section { name, line, text } = self;
+        let name = match name {
+            Some(name) => quote!(::std::option::Option::Some(#name)),
+            None => quote!(::std::option::Option::None),
+        };
+        quote!(::fixtures::Section::new(#path_tokens, #name, #line, #text))
+    }
+}
+
+/// Splits `contents` into the sections between delimiter lines. The text before the first delimiter, and any other
+/// unnamed sections, are only kept if they contain more than whitespace.
+pub fn split(contents: &str, delimiter: &Delimiter) -> Vec<Section> {
+    let mut sections = Vec::new();
+    let mut section = Section {
+        name: None,
+        line: 1,
+        text: String::new(),
+    };
+    for (index, line) in contents.split_inclusive('\n').enumerate() {
+        match header(line.trim_end_matches(['\n', '\r']), delimiter) {
+            Some(name) => {
+                let next_section = Section {
+                    name,
+                    line: index + 2,
+                    text: String::new(),
+                };
+                push_section(&mut sections, std::mem::replace(&mut section, next_section));
+            }
+            None => section.text.push_str(line),
+        }
+    }
+    push_section(&mut sections, section);
+    sections
+}
+
+fn push_section(sections: &mut Vec<Section>, section: Section) {
+    if section.name.is_some() || !section.text.trim().is_empty() {
+        sections.push(section);
+    }
+}
+
+/// Returns `Some` if the line is a delimiter, along with the name it captured, if any.
+fn header(line: &str, delimiter: &Delimiter) -> Option<Option<String>> {
+    match delimiter {
+        Delimiter::Literal(literal) => (line.trim_end() == literal).then_some(None),
+        Delimiter::Regex(regex) => {
+            let captures = regex.captures(line)?;
+            let name = captures
+                .name("name")
+                .or_else(|| captures.get(1))
+                .map(|name| name.as_str().trim().to_string())
+                .filter(|name| !name.is_empty());
+            Some(name)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use regex::Regex;
+
+    use super::*;
+
+    fn section(name: Option<&str>, line: usize, text: &str) -> Section {
+        Section {
+            name: name.map(str::to_string),
+            line,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn correctly_splits_by_literal() {
+        let contents = "a\n---\nb\nc\n---\n---  \r\nd\n---\n";
+
+        let sections = split(contents, &Delimiter::Literal("---".to_string()));
+
+        assert_eq!(
+            sections,
+            [
+                section(None, 1, "a\n"),
+                section(None, 3, "b\nc\n"),
+                section(None, 7, "d\n"),
+            ]
+        );
+    }
+
+    #[test]
+    fn correctly_splits_by_regex() {
+        let contents = "preamble\n=== first ===\na\n=== second ===\nb\n=== ===\nc\n";
+        let regex = Regex::new("^=== (?<name>.*)===$").unwrap();
+
+        let sections = split(contents, &Delimiter::Regex(regex));
+
+        assert_eq!(
+            sections,
+            [
+                section(None, 1, "preamble\n"),
+                section(Some("first"), 3, "a\n"),
+                section(Some("second"), 5, "b\n"),
+                section(None, 7, "c\n"),
+            ]
+        );
+    }
+
+    #[test]
+    fn correctly_keeps_empty_named_sections() {
+        let contents = "# empty\n# not empty\nx";
+        let regex = Regex::new("^# (.*)$").unwrap();
+
+        let sections = split(contents, &Delimiter::Regex(regex));
+
+        assert_eq!(
+            sections,
+            [
+                section(Some("empty"), 2, ""),
+                section(Some("not empty"), 3, "x"),
+            ]
+        );
+    }
+}
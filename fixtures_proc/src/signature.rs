@@ -0,0 +1,286 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    visit::Visit, FnArg, GenericParam, Lifetime, Pat, ReturnType, Signature, Type, TypeImplTrait,
+    TypeReference,
+};
+
+/// Returns the `EXPANSIONS` constant.
+///
+/// If the expansions can be collected into a slice of function pointers, `EXPANSIONS` is a slice of the expansions
+/// themselves. For async functions, each expansion is wrapped in a closure which boxes the returned future.
+///
+/// Otherwise, for example if the signature is polymorphic, or `fn_pointers` is false, `EXPANSIONS` is a slice of
+/// `Expansion`s, whose `call` method has the signature of the expansions, and evaluates the body of the expansion.
+///
+/// `remaining_args` are the parameters of the decorated function, excluding the fixture parameter.
+pub fn expansions_const(
+    sig: &Signature,
+    remaining_args: &[&FnArg],
+    expansion_paths: &[TokenStream],
+    expansion_bodies: &[TokenStream],
+    fn_pointers: bool,
+) -> TokenStream {
+    let expansions_type = match expansions_type(sig, remaining_args) {
+        Some(expansions_type) if fn_pointers => expansions_type,
+        _ => return expansions_dispatcher(sig, remaining_args, expansion_bodies),
+    };
+    if sig.asyncness.is_none() {
+        return quote! {
+            pub const EXPANSIONS: &[#expansions_type] = &[#(#expansion_paths),*];
+        };
+    }
+
+    let arg_idents = remaining_args
+        .iter()
+        .filter_map(|arg| match arg {
+            FnArg::Typed(pat_ty) => match pat_ty.pat.as_ref() {
+                Pat::Ident(pat_ident) => Some(&pat_ident.ident),
+                _ => None,
+            },
+            FnArg::Receiver(_) => None,
+        })
+        .collect::<Vec<_>>();
+    let expansions = expansion_paths
+        .iter()
+        .map(|path| quote!(|#(#arg_idents),*| ::std::boxed::Box::pin(#path(#(#arg_idents),*))));
+    quote! {
+        pub const EXPANSIONS: &[#expansions_type] = &[#(#expansions),*];
+    }
+}
+
+/// Returns the `Expansion` type and the `EXPANSIONS` constant, for expansions which can't be referred to using
+/// function pointers. Since `call` is generic over the same parameters as the decorated function, `EXPANSIONS` itself
+/// stays monomorphic.
+fn expansions_dispatcher(
+    sig: &Signature,
+    remaining_args: &[&FnArg],
+    expansion_bodies: &[TokenStream],
+) -> TokenStream {
+    let Signature {
+        asyncness,
+        unsafety,
+        generics,
+        output,
+        ..
+    } = sig;
+    let where_clause = &generics.where_clause;
+    let indices = 0..expansion_bodies.len();
+    let values = indices.clone();
+    quote! {
+        #[derive(Clone, Copy)]
+        pub struct Expansion(usize);
+
+        impl Expansion {
+            pub #asyncness #unsafety fn call #generics(self, #(#remaining_args),*) #output
+            #where_clause
+            {
+                match self.0 {
+                    #(#indices => #expansion_bodies,)*
+                    _ => ::std::unreachable!(),
+                }
+            }
+        }
+
+        pub const EXPANSIONS: &[Expansion] = &[#(Expansion(#values)),*];
+    }
+}
+
+/// Returns the function pointer type of the expansions. The expansions of async functions return a boxed future.
+fn expansions_type(sig: &Signature, remaining_args: &[&FnArg]) -> Option<TokenStream> {
+    if sig.variadic.is_some()
+        || sig.generics.where_clause.is_some()
+        || output_type(&sig.output).is_some_and(contains_impl_trait)
+    {
+        return None;
+    }
+
+    let mut lifetimes = Vec::new();
+    for param in &sig.generics.params {
+        match param {
+            // Bounded lifetimes can't be expressed using higher-ranked trait bounds
+            GenericParam::Lifetime(lifetime_param) if lifetime_param.bounds.is_empty() => {
+                lifetimes.push(&lifetime_param.lifetime);
+            }
+            _ => return None,
+        }
+    }
+
+    let mut arg_types = Vec::new();
+    let mut borrows = false;
+    for arg in remaining_args {
+        let FnArg::Typed(pat_ty) = arg else {
+            return None;
+        };
+        if contains_impl_trait(&pat_ty.ty) {
+            return None;
+        }
+        let ty = &pat_ty.ty;
+        borrows |= contains_lifetime(ty);
+        // Patterns such as `mut value` aren't allowed in function pointer types, so only the identifier is kept
+        arg_types.push(match pat_ty.pat.as_ref() {
+            Pat::Ident(pat_ident) => {
+                let ident = &pat_ident.ident;
+                quote!(#ident: #ty)
+            }
+            _ => quote!(#ty),
+        });
+    }
+
+    let Signature {
+        asyncness,
+        unsafety,
+        abi,
+        output,
+        ..
+    } = sig;
+
+    if asyncness.is_some() {
+        // The boxed future would have to capture the lifetimes of the parameters, which can't be named if they're
+        // elided, so only futures which don't borrow anything are supported
+        if unsafety.is_some()
+            || !lifetimes.is_empty()
+            || borrows
+            || output_type(output).is_some_and(contains_lifetime)
+        {
+            return None;
+        }
+        let output_type = match output_type(output) {
+            Some(ty) => quote!(#ty),
+            None => quote!(()),
+        };
+        return Some(quote! {
+            fn(#(#arg_types),*) -> ::std::pin::Pin<
+                ::std::boxed::Box<dyn ::std::future::Future<Output = #output_type>>
+            >
+        });
+    }
+
+    let maybe_for_lifetimes = if lifetimes.is_empty() {
+        TokenStream::new()
+    } else {
+        quote!(for<#(#lifetimes),*>)
+    };
+    Some(quote!(#maybe_for_lifetimes #unsafety #abi fn(#(#arg_types),*) #output))
+}
+
+fn contains_impl_trait(ty: &Type) -> bool {
+    struct FindImplTrait(bool);
+
+    impl Visit<'_> for FindImplTrait {
+        fn visit_type_impl_trait(&mut self, _: &TypeImplTrait) {
+            self.0 = true;
+        }
+    }
+
+    let mut visitor = FindImplTrait(false);
+    visitor.visit_type(ty);
+    visitor.0
+}
+
+fn contains_lifetime(ty: &Type) -> bool {
+    struct FindLifetime(bool);
+
+    impl Visit<'_> for FindLifetime {
+        fn visit_lifetime(&mut self, _: &Lifetime) {
+            self.0 = true;
+        }
+
+        fn visit_type_reference(&mut self, _: &TypeReference) {
+            self.0 = true;
+        }
+    }
+
+    let mut visitor = FindLifetime(false);
+    visitor.visit_type(ty);
+    visitor.0
+}
+
+fn output_type(output: &ReturnType) -> Option<&Type> {
+    match output {
+        ReturnType::Default => None,
+        ReturnType::Type(_, ty) => Some(ty),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::ItemFn;
+
+    use super::*;
+
+    fn expansions_type_of(input: &str) -> Option<String> {
+        let item_fn: ItemFn = syn::parse_str(input).expect("Failed to parse function");
+        let remaining_args = item_fn.sig.inputs.iter().skip(1).collect::<Vec<_>>();
+        expansions_type(&item_fn.sig, &remaining_args).map(|tokens| tokens.to_string())
+    }
+
+    #[test]
+    fn correctly_expands_monomorphic_signatures() {
+        assert_eq!(
+            expansions_type_of("fn test(path: &Path, mut owned: String) -> bool {}"),
+            Some("fn (owned : String) -> bool".to_string())
+        );
+        assert_eq!(
+            expansions_type_of(r#"unsafe extern "C" fn test(path: &Path) {}"#),
+            Some(r#"unsafe extern "C" fn ()"#.to_string())
+        );
+    }
+
+    #[test]
+    fn correctly_expands_lifetime_generic_signatures() {
+        assert_eq!(
+            expansions_type_of("fn test<'a>(path: &Path, input: &'a str) -> &'a str {}"),
+            Some("for < 'a > fn (input : & 'a str) -> & 'a str".to_string())
+        );
+    }
+
+    #[test]
+    fn returns_none_for_polymorphic_signatures() {
+        for input in [
+            "fn test<T>(path: &Path, value: T) {}",
+            "fn test<const N: usize>(path: &Path) {}",
+            "fn test(path: &Path, value: impl Display) {}",
+            "fn test(path: &Path, value: Box<impl Display>) {}",
+            "fn test<'a, 'b: 'a>(path: &Path, value: &'a &'b str) {}",
+            "fn test<T>(path: &Path, value: Vec<T>) where T: Clone {}",
+            "fn test(path: &Path) -> impl Display {}",
+            "async fn test(path: &Path, value: &str) {}",
+            "async fn test<'a>(path: &Path, value: Cow<'a, str>) {}",
+            "async unsafe fn test(path: &Path) {}",
+        ] {
+            assert_eq!(expansions_type_of(input), None, "Expected None for {input}");
+        }
+    }
+
+    #[test]
+    fn correctly_expands_async_signatures() {
+        assert_eq!(
+            expansions_type_of("async fn test(path: &Path, value: String) -> usize {}"),
+            Some(
+                "fn (value : String) -> :: std :: pin :: Pin < :: std :: boxed :: Box < dyn :: std :: future :: Future < Output = usize >> >"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn correctly_dispatches_polymorphic_expansions() {
+        let item_fn: ItemFn =
+            syn::parse_str("fn test<T: Display>(path: &Path, value: T) -> bool {}")
+                .expect("Failed to parse function");
+        let remaining_args = item_fn.sig.inputs.iter().skip(1).collect::<Vec<_>>();
+
+        let tokens = expansions_const(
+            &item_fn.sig,
+            &remaining_args,
+            &[quote!(file_1_dot_txt)],
+            &[quote!({ test(path, value) })],
+            true,
+        )
+        .to_string();
+
+        assert!(tokens.contains("pub fn call < T : Display > (self , value : T) -> bool"));
+        assert!(tokens.contains("pub const EXPANSIONS : & [Expansion] = & [Expansion (0usize)] ;"));
+    }
+}
@@ -0,0 +1,106 @@
+use syn::{AttrStyle, Attribute, Path};
+
+/// Test attributes which are recognized by default, in addition to those registered using the `test_attrs` option.
+/// Leading `::` are ignored when matching attributes.
+const TEST_ATTRIBUTES: &[&[&str]] = &[
+    &["test"],
+    &["core", "prelude", "v1", "test"],
+    &["std", "prelude", "v1", "test"],
+    &["tokio", "test"],
+    &["async_std", "test"],
+    &["smol_potat", "test"],
+    &["actix_rt", "test"],
+    &["actix_web", "test"],
+    &["test_log", "test"],
+    &["sqlx", "test"],
+    &["wasm_bindgen_test"],
+    &["wasm_bindgen_test", "wasm_bindgen_test"],
+    &["googletest", "test"],
+    &["gtest"],
+];
+
+/// Checks whether the attribute marks a function as a test, e.g. `#[test]` or `#[tokio::test(flavor = "current_thread")]`.
+pub fn is_test_attribute(attr: &Attribute, custom_test_attrs: &[Path]) -> bool {
+    if attr.style != AttrStyle::Outer {
+        return false;
+    }
+    let path = attr.path();
+    TEST_ATTRIBUTES
+        .iter()
+        .any(|segments| path_is(path, segments.iter().copied()))
+        || custom_test_attrs.iter().any(|custom_test_attr| {
+            path_is(
+                path,
+                custom_test_attr
+                    .segments
+                    .iter()
+                    .map(|segment| segment.ident.to_string()),
+            )
+        })
+}
+
+fn path_is<S: AsRef<str>>(path: &Path, segments: impl ExactSizeIterator<Item = S>) -> bool {
+    path.segments.len() == segments.len()
+        && path
+            .segments
+            .iter()
+            .zip(segments)
+            .all(|(actual_segment, segment)| {
+                actual_segment.ident == segment && actual_segment.arguments.is_none()
+            })
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::*;
+
+    #[test]
+    fn correctly_identifies_test_attributes() {
+        let attrs: [Attribute; 6] = [
+            parse_quote!(#[test]),
+            parse_quote!(#[::core::prelude::v1::test]),
+            parse_quote!(#[tokio::test]),
+            parse_quote!(#[tokio::test(flavor = "multi_thread")]),
+            parse_quote!(#[async_std::test]),
+            parse_quote!(#[wasm_bindgen_test]),
+        ];
+
+        for attr in attrs {
+            assert!(is_test_attribute(&attr, &[]));
+        }
+    }
+
+    #[test]
+    fn correctly_identifies_non_test_attributes() {
+        let attrs: [Attribute; 4] = [
+            parse_quote!(#[ignore]),
+            parse_quote!(#[tokio::main]),
+            parse_quote!(#[test::other]),
+            parse_quote!(#![test]),
+        ];
+
+        for attr in attrs {
+            assert!(!is_test_attribute(&attr, &[]));
+        }
+    }
+
+    #[test]
+    fn correctly_identifies_custom_test_attributes() {
+        let custom_test_attrs: [Path; 2] = [parse_quote!(my_crate::test), parse_quote!(::my_test)];
+
+        assert!(is_test_attribute(
+            &parse_quote!(#[my_crate::test]),
+            &custom_test_attrs
+        ));
+        assert!(is_test_attribute(
+            &parse_quote!(#[my_test(timeout = 10)]),
+            &custom_test_attrs
+        ));
+        assert!(!is_test_attribute(
+            &parse_quote!(#[other_crate::test]),
+            &custom_test_attrs
+        ));
+    }
+}